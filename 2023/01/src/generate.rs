@@ -0,0 +1,29 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+const DIGITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// `size` lines of letters, digits and spelled out digits, each with at least one digit
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut pieces: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| match rng.gen_range(0..3) {
+                0 => rng.gen_range(1..=9).to_string(),
+                1 => DIGITS.choose(rng).unwrap().to_string(),
+                _ => (0..rng.gen_range(1..=5)).map(|_| rng.gen_range('a'..='z')).collect(),
+            })
+            .collect();
+
+        if !pieces.iter().any(|piece| piece.starts_with(|c: char| c.is_ascii_digit())) {
+            let at = rng.gen_range(0..=pieces.len());
+            pieces.insert(at, rng.gen_range(1..=9).to_string());
+        }
+
+        input.push_str(&pieces.concat());
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
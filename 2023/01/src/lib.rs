@@ -1,59 +1,100 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use aoc_core::{Answer, ParseError};
 
-fn main() {
-    let input = read_input("input.txt").unwrap();
-    let mut result: i32 = 0;
+pub mod generate;
 
-    for line in input {
-        println!("Line: {:?}", &line);
-        let parsed_line: SplittedLine = line.unwrap().into();
-        let relevant_number = parsed_line.find_relevant_number();
+pub fn parse(input: &str) -> Result<Vec<SplittedLine>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let parsed_line: SplittedLine = line.parse()?;
 
-        println!("Parsed Line: {:?}", parsed_line);
-        println!("Relevant Number: {:?}", relevant_number);
-        println!();
-        result += relevant_number;
+            if parsed_line.find_relevant_number().is_none() {
+                return Err(ParseError::at(line, format!("no digit in `{}`", line)));
+            }
+
+            Ok(parsed_line)
+        })
+        .collect()
+}
+
+pub fn render(lines: &[SplittedLine]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+pub fn part1(input: &[SplittedLine]) -> Answer {
+    let mut result: u64 = 0;
+
+    // Lines only spelling their digits out (part 2 examples) have no calibration value here
+    for parsed_line in input {
+        let (Some(first), Some(last)) = (parsed_line.first_digit, parsed_line.last_digit) else {
+            continue;
+        };
+
+        let calibration = u64::from(first.to_digit(10).unwrap() * 10 + last.to_digit(10).unwrap());
+        tracing::trace!(line = ?parsed_line, calibration, "calibration value");
+
+        result += calibration;
     }
 
-    println!("{:?}", result);
-    // let split_line: SplittedLine = "twovgtprdzcjjzkq3ffsbcblnpq".to_string().into();
-    // println!("twovgtprdzcjjzkq3ffsbcblnpq");
-    // println!("{:?}", split_line);
-    // println!("{:?}", split_line.find_relevant_number());
-    //
-    // let split_line: SplittedLine = "two8sixbmrmqzrrb1seven".to_string().into();
-    // println!("two8sixbmrmqzrrb1seven");
-    // println!("{:?}", split_line);
-    // println!("{:?}", split_line.find_relevant_number());
+    result.into()
 }
 
-fn read_input<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+pub fn part2(input: &[SplittedLine]) -> Answer {
+    let mut result: u64 = 0;
+
+    for parsed_line in input {
+        let relevant_number = parsed_line
+            .find_relevant_number()
+            .expect("lines without digits are rejected while parsing");
+
+        tracing::trace!(line = ?parsed_line, calibration = relevant_number, "calibration value");
+
+        result += relevant_number;
+    }
+
+    result.into()
 }
 
-#[derive(Debug)]
-struct SplittedLine {
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplittedLine {
     head: Option<String>,
     tail: Option<String>,
     first_digit: Option<char>,
     last_digit: Option<char>,
 }
 
-impl From<String> for SplittedLine {
-    fn from(s: String) -> Self {
+impl FromStr for SplittedLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(c) = s.chars().find(|c| !c.is_ascii()) {
+            return Err(aoc_core::parse::unexpected(c).with_location(&s[s.find(c).unwrap()..]));
+        }
+
         let first_digit_position: Option<usize> = s.chars().position(|c| c.is_ascii_digit());
         let last_digit_position: Option<usize> = s.chars().rev().position(|c| c.is_ascii_digit()).map(|x| s.len() - x - 1);
-         Self {
+        // Without a digit the whole line is the head, it has no tail
+        Ok(Self {
             head: if let Some(position) = first_digit_position { Some(s[..position].to_string()) } else { Some(s[..].to_string())},
-            tail: if let Some(position) = last_digit_position { Some(s[position + 1..].to_string()) } else { Some(s[..].to_string())},
-            first_digit: if let Some(position) = first_digit_position { Some(s.chars().nth(position).unwrap().clone()) } else { None },
-            last_digit: if let Some(position) = last_digit_position { Some(s.chars().nth(position).unwrap().clone()) } else { None }
+            tail: last_digit_position.map(|position| s[position + 1..].to_string()),
+            first_digit: first_digit_position.map(|position| s.chars().nth(position).unwrap()),
+            last_digit: last_digit_position.map(|position| s.chars().nth(position).unwrap())
+        })
+    }
+}
+
+// Whatever stood between the first and the last digits is lost, they are written side by side
+impl fmt::Display for SplittedLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in [&self.head, &self.first_digit.map(String::from), &self.last_digit.map(String::from), &self.tail] {
+            write!(f, "{}", part.as_deref().unwrap_or_default())?;
         }
+
+        Ok(())
     }
 }
 
@@ -94,9 +135,9 @@ impl SplittedLine {
 
                     if word_length == 5 {
                         word_length = 3;
-                        offset = offset + 1;
+                        offset += 1;
                     } else {
-                        word_length = word_length + 1;
+                        word_length += 1;
                     }
                 }
                 match result {
@@ -121,7 +162,7 @@ impl SplittedLine {
             ("EIGHT".to_string(), '8'),
             ("NINE".to_string(), '9')
         ]);
-        if let Some(tail) = &self.tail {
+        if let Some(tail) = self.tail.as_ref().or(self.head.as_ref()) {
             let tail_length: usize = tail.len();
 
             if tail_length < 3 {
@@ -146,9 +187,9 @@ impl SplittedLine {
 
                     if word_length == 5 {
                         word_length = 3;
-                        offset = offset + 1;
+                        offset += 1;
                     } else {
-                        word_length = word_length + 1;
+                        word_length += 1;
                     }
                 }
                 match result {
@@ -162,12 +203,12 @@ impl SplittedLine {
 
     }
 
-    fn find_relevant_number(&self) -> i32 {
+    fn find_relevant_number(&self) -> Option<u64> {
         let mut relevant_number_str: String = String::new();
 
-        relevant_number_str.push(self.find_first_digit().unwrap());
-        relevant_number_str.push(self.find_last_digit().unwrap());
+        relevant_number_str.push(self.find_first_digit()?);
+        relevant_number_str.push(self.find_last_digit()?);
 
-        relevant_number_str.parse().unwrap()
+        relevant_number_str.parse().ok()
     }
 }
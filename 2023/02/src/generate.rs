@@ -0,0 +1,26 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+// `size` games of one to six sets, each showing up to 20 cubes of some of the colors
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut input = String::new();
+
+    for id in 1..=size {
+        let sets: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+
+                colors[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+
+        input.push_str(&format!("Game {}: {}\n", id, sets.join("; ")));
+    }
+
+    Generated::new(input)
+}
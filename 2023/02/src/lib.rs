@@ -0,0 +1,184 @@
+use std::{collections::HashMap, fmt, str::FromStr, sync::LazyLock};
+use regex::Regex;
+use aoc_core::{parse, Answer, Parameters, Params, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(str::parse).collect()
+}
+
+pub fn render(input: &[Game]) -> String {
+    input.iter().map(|game| format!("{}\n", game)).collect()
+}
+
+pub fn part1(input: &[Game], config: Config) -> Answer {
+    let mut result: u64 = 0;
+
+    for game in input {
+        if game.is_possible_with_given_configuration(config.blue, config.green, config.red) {
+            result += game.id;
+        }
+    }
+
+    result.into()
+}
+
+pub fn part2(input: &[Game]) -> Answer {
+    let mut result: u64 = 0;
+
+    for game in input {
+        let minimum_set_of_cubes = game.find_minimum_set_of_cubes();
+        result += minimum_set_of_cubes.into_iter().product::<u64>()
+    }
+
+    result.into()
+}
+
+// Cubes in the bag
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub red: u64,
+    pub green: u64,
+    pub blue: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { red: 12, green: 13, blue: 14 }
+    }
+}
+
+impl Parameters for Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+
+        Ok(Self {
+            red: params.take("red", default.red)?,
+            green: params.take("green", default.green)?,
+            blue: params.take("blue", default.blue)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Set {
+    blue: Option<u64>,
+    green: Option<u64>,
+    red: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
+    id: u64,
+    sets: Vec<Set>,
+}
+
+static GAME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Game (?<id>\d+): (?<sets>.+)").unwrap());
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = GAME
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, format!("expected `Game <id>: <sets>`, found `{}`", s)))?;
+
+        let id: u64 = parse::number(&captures["id"])?;
+
+        let sets: Vec<Set> = captures["sets"].split(';')
+            .map(|s| s.trim().split(", ").collect())
+            .map(|v: Vec<&str>| {
+                let mut set_map: HashMap<&str, u64> = HashMap::new();
+
+                for item in v {
+                    let (quantity, color) = parse::split_once(item, " ")?;
+
+                    if !["blue", "green", "red"].contains(&color) {
+                        return Err(ParseError::at(color, format!("unknown color `{}`", color)));
+                    }
+
+                    set_map.insert(color, parse::number(quantity)?);
+                }
+
+                Ok(set_map)
+            })
+            .map(|h: Result<HashMap<&str, u64>, ParseError>| h.map(|mut h| Set{ blue: h.remove("blue"), green: h.remove("green"), red: h.remove("red") }))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id,
+            sets
+        })
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets: Vec<String> = self.sets
+            .iter()
+            .map(|set| {
+                [(set.blue, "blue"), (set.green, "green"), (set.red, "red")]
+                    .into_iter()
+                    .filter_map(|(quantity, color)| quantity.map(|quantity| format!("{} {}", quantity, color)))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+
+        write!(f, "Game {}: {}", self.id, sets.join("; "))
+    }
+}
+
+impl Game {
+    fn is_possible_with_given_configuration(&self, blue: u64, green: u64, red:u64) -> bool {
+        for set in &self.sets {
+            if let Some(quantity) = set.blue {
+                if quantity > blue {
+                    return false;
+                }
+            }
+            if let Some(quantity) = set.green {
+                if quantity > green {
+                    return false;
+                }
+            }
+            if let Some(quantity) = set.red {
+                if quantity > red {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn find_minimum_set_of_cubes(&self) -> Vec<u64> {
+        let mut blue: u64 = 0;
+        let mut green: u64 = 0;
+        let mut red: u64 = 0;
+
+        for set in &self.sets {
+            if let Some(quantity) = set.blue {
+                if quantity > blue {
+                    blue = quantity;
+                }
+            }
+            if let Some(quantity) = set.green {
+                if quantity > green {
+                    green = quantity;
+                }
+            }
+            if let Some(quantity) = set.red {
+                if quantity > red {
+                    red = quantity;
+                }
+            }
+        }
+
+        vec![blue, green, red]
+    }
+}
+
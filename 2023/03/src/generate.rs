@@ -0,0 +1,34 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '=', '%', '@'];
+
+// A `size` × `size` schematic of numbers of up to three digits and symbols scattered in the dots
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..side {
+        let mut row = String::new();
+
+        while row.len() < side {
+            match rng.gen_range(0..10) {
+                0..=1 => {
+                    let digits = rng.gen_range(1..=3).min(side - row.len());
+                    row.push_str(&rng.gen_range(10_u32.pow(digits as u32 - 1)..10_u32.pow(digits as u32)).to_string());
+                },
+                2 => row.push(*SYMBOLS.choose(rng).unwrap()),
+                _ => {},
+            }
+            // Two numbers side by side would read as one
+            if row.len() < side {
+                row.push('.');
+            }
+        }
+
+        input.push_str(&row);
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,159 @@
+use std::{collections::{HashSet, HashMap}, fmt};
+use aoc_core::{Answer, Grid, ParseError, Position};
+
+pub mod generate;
+
+// --------------------
+// Entrypoint
+// --------------------
+pub fn parse(input: &str) -> Result<EngineSchematic, ParseError> {
+    Ok(EngineSchematic { schematic: input.parse()? })
+}
+
+pub fn render(engine_schematic: &EngineSchematic) -> String {
+    engine_schematic.schematic.to_string()
+}
+
+pub fn part1(engine_schematic: &EngineSchematic) -> Answer {
+    engine_schematic.sum_part_numbers().into()
+}
+
+pub fn part2(engine_schematic: &EngineSchematic) -> Answer {
+    engine_schematic.get_gear_ratios_sum().into()
+}
+
+// --------------------
+// NodeType - Represents what we have on each node of the schematic
+// --------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeType {
+    Number(char),
+    Symbol(char),
+    Empty,
+}
+
+impl From<char> for NodeType {
+    fn from(c: char) -> Self {
+        match c {
+            '0'..='9' => NodeType::Number(c),
+            '.' => NodeType::Empty,
+            _ => NodeType::Symbol(c)
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeType::Number(c) | NodeType::Symbol(c) => write!(f, "{}", c),
+            NodeType::Empty => write!(f, "."),
+        }
+    }
+}
+
+// --------------------
+// IntelligentCoordinates - Able to add neighbours while filtering based on constraints
+// --------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct IntelligentCoordinates {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "aoc_core::canonical::sorted"))]
+    coordinates: HashSet<Position>
+}
+
+impl IntelligentCoordinates {
+    fn new() -> Self{
+        Self {
+            coordinates: HashSet::new()
+        }
+    }
+
+    fn add_neighbours<T>(&mut self, coordinate: Position, grid: &Grid<T>) {
+        self.coordinates.extend(grid.neighbours8(coordinate));
+    }
+}
+
+// --------------------
+// EngineSchematic - Knows how to sum the part numbers and the gear ratios
+// --------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EngineSchematic {
+    schematic: Grid<NodeType>
+}
+
+impl EngineSchematic {
+    fn sum_part_numbers(&self) -> u64 {
+        let mut result: u64 = 0;
+
+        let mut current_number: String = String::new();
+        let mut coordinates_to_check = IntelligentCoordinates::new();
+
+        for (position, item) in self.schematic.iter() {
+            match item {
+                NodeType::Number(num) => {
+                    coordinates_to_check.add_neighbours(position, &self.schematic);
+                    current_number.push(*num)
+                },
+                _ => {
+                    if current_number.is_empty() {
+                        continue;
+                    } else {
+                        for coordinate in coordinates_to_check.coordinates.iter() {
+                            match self.schematic[*coordinate] {
+                                NodeType::Symbol(_) => {
+                                    result += current_number.parse::<u64>().unwrap();
+                                    break;
+                                },
+                                _ => {
+                                    continue;
+                                }
+                            }
+                        }
+                        coordinates_to_check = IntelligentCoordinates::new();
+                        current_number = String::new();
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn get_gear_ratios_sum(&self) -> u64 {
+        let mut possible_gears_map: HashMap<Position, Vec<u64>> = HashMap::new();
+
+        let mut current_number: String = String::new();
+        let mut coordinates_to_check = IntelligentCoordinates::new();
+
+        for (position, item) in self.schematic.iter() {
+            match item {
+                NodeType::Number(num) => {
+                    coordinates_to_check.add_neighbours(position, &self.schematic);
+                    current_number.push(*num)
+                },
+                _ => {
+                    if current_number.is_empty() {
+                        continue;
+                    } else {
+                        for coordinate in coordinates_to_check.coordinates.into_iter() {
+                            match self.schematic[coordinate] {
+                                NodeType::Symbol('*') => {
+                                    possible_gears_map.entry(coordinate)
+                                        .and_modify(|parts| parts.push(current_number.parse().unwrap()))
+                                        .or_insert(vec![current_number.parse().unwrap()]);
+                                },
+                                _ => {
+                                    continue;
+                                }
+                            }
+                        }
+                        coordinates_to_check = IntelligentCoordinates::new();
+                        current_number = String::new();
+                    }
+                }
+            }
+        }
+        possible_gears_map.iter().filter(|(_, parts)| parts.len() > 1).fold(0, |acc, (_, parts)| acc + (parts[0] * parts[1]))
+    }
+}
@@ -0,0 +1,25 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+// `size` cards of 10 winning numbers and 25 numbers, the matches of a card never win copies of
+// cards past the end of the table
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let width = size.to_string().len();
+    let mut input = String::new();
+
+    for id in 1..=size {
+        let mut pool: Vec<u32> = (1..100).collect();
+        pool.shuffle(rng);
+
+        let (winning, rest) = pool.split_at(10);
+        let matches = if rng.gen_bool(0.5) { 0 } else { rng.gen_range(1..=10) }.min(size - id);
+
+        let mut numbers: Vec<u32> = winning[..matches].iter().chain(&rest[..25 - matches]).copied().collect();
+        numbers.shuffle(rng);
+
+        let row = |numbers: &[u32]| numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<String>>().join(" ");
+        input.push_str(&format!("Card {:>width$}: {} | {}\n", id, row(winning), row(&numbers)));
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,130 @@
+use std::{collections::{HashSet, HashMap}, fmt, str::FromStr, sync::LazyLock};
+use regex::Regex;
+use itertools::Itertools;
+use aoc_core::{memo::Memo, parse, Answer, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.lines().map(str::parse).collect()
+}
+
+pub fn render(input: &[Card]) -> String {
+    input.iter().map(|card| format!("{}\n", card)).collect()
+}
+
+pub fn part1(input: &[Card]) -> Answer {
+    let mut total_points: u64 = 0;
+
+    for card in input {
+        total_points += card.calculate_points();
+    }
+
+    total_points.into()
+}
+
+pub fn part2(input: &[Card]) -> Answer {
+    let mut card_stack: CardStack = CardStack::new();
+
+    for card in input {
+        card_stack.add(card);
+    }
+
+    card_stack.process().into()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Card {
+    id: usize,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "aoc_core::canonical::sorted"))]
+    winning_numbers: HashSet<usize>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "aoc_core::canonical::sorted"))]
+    numbers: HashSet<usize>
+}
+
+static CARD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Card\s+(?<id>\d+): (?<wnumbers>[0-9\s]+)\|(?<numbers>[0-9\s]+)").unwrap());
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = CARD
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, format!("expected `Card <id>: <numbers> | <numbers>`, found `{}`", s)))?;
+
+        let id: usize = parse::number(&captures["id"])?;
+        let winning_numbers:HashSet<usize> = captures["wnumbers"].trim().split(' ')
+            .filter(|s| !s.is_empty())
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let numbers: HashSet<usize> = captures["numbers"].trim().split(' ')
+            .filter(|s| !s.is_empty())
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id,
+            winning_numbers,
+            numbers
+        })
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sorted = |numbers: &HashSet<usize>| numbers.iter().sorted().join(" ");
+
+        write!(f, "Card {}: {} | {}", self.id, sorted(&self.winning_numbers), sorted(&self.numbers))
+    }
+}
+
+impl Card {
+    fn count_matches(&self) -> usize {
+        self.winning_numbers.intersection(&self.numbers).count()
+    }
+
+    fn calculate_points(&self) -> u64 {
+        match self.count_matches() {
+            0 => 0,
+            matches => 1 << (matches - 1),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct CardStack<'a> {
+    cards: HashMap<usize, &'a Card>
+}
+
+impl<'a> CardStack<'a> {
+    fn new() -> Self {
+        Self {
+            cards: HashMap::new()
+        }
+    }
+
+    fn add(&mut self, card: &'a Card) {
+        self.cards.entry(card.id).or_insert(card);
+    }
+
+    // The cards from the last one up, so that the cards a card wins are already counted
+    fn process(&self) -> usize {
+        let mut memo: Memo<usize, usize> = Memo::new();
+
+        self.cards.keys().sorted().rev().map(|card_id| self.count_cards(*card_id, &mut memo)).sum()
+    }
+
+    // The card and the copies it wins, down to the copies these win in turn
+    fn count_cards(&self, card_id: usize, memo: &mut Memo<usize, usize>) -> usize {
+        memo.get_or_insert_with(card_id, |memo| {
+            let points = self.cards[&card_id].count_matches();
+
+            1 + (card_id + 1..=card_id + points)
+                .filter(|id| self.cards.contains_key(id))
+                .map(|id| self.count_cards(id, memo))
+                .sum::<usize>()
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// Every value is below this, like in the puzzle
+const LIMIT: i64 = 1 << 32;
+
+// Ten seed ranges and 7 maps shuffling `size` ranges of the values each, the ranges of a map cover
+// all of the values so that it can be walked both ways
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut seeds: Vec<String> = vec![];
+    for _ in 0..10 {
+        let start = rng.gen_range(0..LIMIT);
+        let length = rng.gen_range(1..=(LIMIT - start).min(LIMIT / 20));
+        seeds.push(format!("{} {}", start, length));
+    }
+
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for map in MAPS {
+        let mut cuts: Vec<i64> = (1..size).map(|_| rng.gen_range(1..LIMIT)).collect();
+        cuts.extend([0, LIMIT]);
+        cuts.sort();
+        cuts.dedup();
+
+        let sources: Vec<(i64, i64)> = cuts.windows(2).map(|cut| (cut[0], cut[1] - cut[0])).collect();
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        order.shuffle(rng);
+
+        input.push_str(&format!("\n{} map:\n", map));
+
+        let mut destination: i64 = 0;
+        for index in order {
+            let (source, length) = sources[index];
+            input.push_str(&format!("{} {} {}\n", destination, source, length));
+            destination += length;
+        }
+    }
+
+    Generated::new(input)
+}
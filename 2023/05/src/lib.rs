@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+use aoc_core::{interval::{IntervalSet, LinearMap}, parse, Answer, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    input.parse()
+}
+
+pub fn render(almanac: &Almanac) -> String {
+    almanac.to_string()
+}
+
+pub fn part1(almanac: &Almanac) -> Answer {
+    let nearest_location = almanac
+        .seeds_to_location()
+        .into_iter()
+        .min()
+        .expect("parsing the seeds yields at least one");
+
+    nearest_location.into()
+}
+
+pub fn part2(almanac: &Almanac) -> Answer {
+    almanac.find_nearest_location().into()
+}
+
+// Each line of a map moves a range of sources by the offset to its destinations
+fn input_string_to_almanac_map(s: &str) -> Result<LinearMap, ParseError> {
+    let mut map: LinearMap = LinearMap::new();
+
+    for item in s.split('\n').skip(1) {
+        if item.is_empty() {
+            continue;
+        }
+        let split: Vec<&str> = item.split(' ').collect();
+        let [destination, source, range] = split[..] else {
+            return Err(ParseError::at(item, format!("expected three numbers, found `{}`", item)));
+        };
+        let destination_range_start: i64 = parse::number(destination)?;
+        let source_range_start: i64 = parse::number(source)?;
+        let range: i64 = parse::number(range)?;
+        if range <= 0 {
+            return Err(ParseError::at(item, format!("expected a positive range length, found `{}`", item)));
+        }
+
+        let sources = source_range_start.checked_add(range).map(|end| source_range_start..end);
+        let offset = destination_range_start.checked_sub(source_range_start);
+        match (sources, offset) {
+            (Some(sources), Some(offset)) if LinearMap::fits(&sources, offset) => map.insert(sources, offset),
+            _ => return Err(ParseError::at(item, format!("expected the ranges to fit in an i64, found `{}`", item))),
+        }
+    }
+    Ok(map)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    seed_to_soil: LinearMap,
+    soil_to_fertilizer: LinearMap,
+    fertilizer_to_water: LinearMap,
+    water_to_light: LinearMap,
+    light_to_temperature: LinearMap,
+    temperature_to_humidity: LinearMap,
+    humidity_to_location: LinearMap
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let contents: Vec<&str> = s.trim_end().split("\n\n").collect();
+        if contents.len() != 8 {
+            return Err(ParseError::at(s, format!("expected the seeds and 7 maps, found {} sections", contents.len())));
+        }
+
+        // Seeds, read as `<start> <length>` pairs in part 2
+        let seeds: Vec<i64> = contents[0]
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::at(contents[0], "expected `seeds: `"))?
+            .split(' ')
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        if !seeds.len().is_multiple_of(2) {
+            return Err(ParseError::at(contents[0], "expected the seeds to come in pairs"));
+        }
+        if seeds.chunks_exact(2).any(|pair| pair[0].checked_add(pair[1]).is_none()) {
+            return Err(ParseError::at(contents[0], "expected the seed ranges to fit in an i64"));
+        }
+
+        // Maps
+        let seed_to_soil = input_string_to_almanac_map(contents[1])?;
+        let soil_to_fertilizer = input_string_to_almanac_map(contents[2])?;
+        let fertilizer_to_water = input_string_to_almanac_map(contents[3])?;
+        let water_to_light = input_string_to_almanac_map(contents[4])?;
+        let light_to_temperature = input_string_to_almanac_map(contents[5])?;
+        let temperature_to_humidity = input_string_to_almanac_map(contents[6])?;
+        let humidity_to_location = input_string_to_almanac_map(contents[7])?;
+
+        Ok(Self {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location
+        })
+    }
+}
+
+// The maps are written from their pieces, under the names they have in the puzzle
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        writeln!(f)?;
+
+        let maps = [
+            ("seed-to-soil", &self.seed_to_soil),
+            ("soil-to-fertilizer", &self.soil_to_fertilizer),
+            ("fertilizer-to-water", &self.fertilizer_to_water),
+            ("water-to-light", &self.water_to_light),
+            ("light-to-temperature", &self.light_to_temperature),
+            ("temperature-to-humidity", &self.temperature_to_humidity),
+            ("humidity-to-location", &self.humidity_to_location),
+        ];
+        for (name, map) in maps {
+            writeln!(f, "\n{} map:", name)?;
+            for (sources, offset) in map.pieces() {
+                writeln!(f, "{} {} {}", sources.start + offset, sources.start, sources.end - sources.start)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Almanac {
+    fn maps(&self) -> [&LinearMap; 7] {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+    }
+
+    fn seeds_to_location(&self) -> Vec<i64> {
+        self.seeds.iter()
+            .map(|&seed| self.maps().iter().fold(seed, |value, map| map.get(value)))
+            .collect()
+    }
+
+    // The seed ranges go through the maps whole, split wherever the pieces of a map split them
+    fn find_nearest_location(&self) -> i64 {
+        let seeds: IntervalSet = self.seeds.chunks_exact(2).map(|pair| pair[0]..pair[0] + pair[1]).collect();
+
+        let locations = self.maps().iter().fold(seeds, |values, map| map.image(&values));
+
+        locations.min().unwrap_or(0)
+    }
+}
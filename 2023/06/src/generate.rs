@@ -0,0 +1,37 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// `size` races of 10 to 99 milliseconds whose records can be beaten. Part 2 reads them all
+// together, and the digits of more than a few races do not fit in a u64.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let times: Vec<u64> = (0..size.max(1)).map(|_| rng.gen_range(10..100)).collect();
+    let distances: Vec<u64> = times.iter().map(|&time| rng.gen_range(0..farthest(time))).collect();
+
+    races(&times, &distances)
+}
+
+// `size` races (4 at most) like in the puzzle, whose record can also be beaten when read all
+// together. The farthest distance of the race read all together grows with the square of its time,
+// more digits than these would not fit in a u64.
+pub fn puzzle_like<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let races = size.clamp(1, 4);
+    let kerned = |numbers: &[u64]| numbers.iter().map(u64::to_string).collect::<String>().parse::<u64>().unwrap();
+
+    loop {
+        let times: Vec<u64> = (0..races).map(|_| rng.gen_range(10..100)).collect();
+        let distances: Vec<u64> = times.iter().map(|&time| rng.gen_range(0..farthest(time))).collect();
+
+        if kerned(&distances) < farthest(kerned(&times)) {
+            return self::races(&times, &distances);
+        }
+    }
+}
+
+fn farthest(time: u64) -> u64 {
+    (time / 2) * (time - time / 2)
+}
+
+fn races(times: &[u64], distances: &[u64]) -> Generated {
+    let line = |numbers: &[u64]| numbers.iter().map(|n| format!("{:>5}", n)).collect::<String>();
+    Generated::new(format!("Time:    {}\nDistance:{}\n", line(times), line(distances)))
+}
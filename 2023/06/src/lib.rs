@@ -0,0 +1,107 @@
+use num_bigint::BigUint;
+use aoc_core::{math, parse, Answer, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<AllRaces, ParseError> {
+    let mut lines = input.lines();
+    let (time_line, distance_line) = (lines.next().unwrap_or(input), lines.next().unwrap_or(input));
+
+    let times = parse_numbers(time_line, "Time:")?;
+    let distances = parse_numbers(distance_line, "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(ParseError::at(input, format!("expected as many distances as times, found {} and {}", distances.len(), times.len())));
+    }
+
+    Ok(AllRaces { times, distances })
+}
+
+// The numbers are written as they were read, so that the kerned race keeps all of their digits
+pub fn render(races: &AllRaces) -> String {
+    let line = |label: &str, numbers: &[String]| -> String {
+        numbers.iter().fold(label.to_string(), |line, n| format!("{} {}", line, n)) + "\n"
+    };
+
+    line("Time:", &races.times) + &line("Distance:", &races.distances)
+}
+
+// The product grows with every race, there can be more of them than a u64 holds
+pub fn part1(races: &AllRaces) -> Result<Answer, ParseError> {
+    let result: BigUint = races.races()?.iter()
+        .map(|race| race.determine_number_of_ways_to_beat_the_record().map(BigUint::from))
+        .product::<Option<BigUint>>()
+        .ok_or_else(too_long)?;
+
+    Ok(result.into())
+}
+
+pub fn part2(races: &AllRaces) -> Result<Answer, ParseError> {
+    Ok(races.kerned()?.determine_number_of_ways_to_beat_the_record().ok_or_else(too_long)?.into())
+}
+
+fn too_long() -> ParseError {
+    ParseError::new("the races are too long to count the ways to win")
+}
+
+fn parse_numbers(line: &str, label: &str) -> Result<Vec<String>, ParseError> {
+    line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(line, format!("expected `{}`", label)))?
+        .split(' ')
+        .filter(|r| !r.is_empty())
+        .map(|token| parse::number::<u64>(token).map(|_| token.to_string()))
+        .collect()
+}
+
+// The spaces between the digits are bad kerning, the whole line is a single number
+fn parse_kerned_number(numbers: &[String], label: &str) -> Result<u64, ParseError> {
+    let digits = numbers.concat();
+
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(format!("expected the kerned {} to be a number, found `{}`", label, digits)))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Race {
+    time: u64,
+    distance: u64
+}
+
+impl Race {
+    fn determine_number_of_ways_to_beat_the_record(&self) -> Option<u64> {
+        // The function that defines the distance is given by:
+        // d = ct * (t - ct) // Where d = distance, ct = charging time, t = time
+        //
+        // The record is beaten when ct * (t - ct) > d, that is ct^2 - t * ct + d < 0, by the
+        // charging times strictly between the roots of the polynomial
+        let time = i64::try_from(self.time).ok()?;
+        let distance = i64::try_from(self.distance).ok()?;
+        let charging_times = math::below_zero(1, -time, distance)?;
+
+        Some((charging_times.end() - charging_times.start() + 1).max(0) as u64)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllRaces {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+impl AllRaces {
+    fn races(&self) -> Result<Vec<Race>, ParseError> {
+        self.times.iter().zip(self.distances.iter())
+            .map(|(time, distance)| Ok(Race { time: parse::number(time)?, distance: parse::number(distance)? }))
+            .collect()
+    }
+
+    // Only the second part reads the kerned race, its digits may not fit in a u64 when the others do
+    fn kerned(&self) -> Result<Race, ParseError> {
+        Ok(Race {
+            time: parse_kerned_number(&self.times, "time")?,
+            distance: parse_kerned_number(&self.distances, "distance")?,
+        })
+    }
+}
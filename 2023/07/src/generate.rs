@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+const CARDS: [char; 13] = ['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'];
+
+// `size` distinct hands (there are 13⁵ of them) with bids up to 1000, a hand mostly repeats a few
+// cards so that every type shows up
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let size = size.min(CARDS.len().pow(5));
+    let mut hands: HashSet<String> = HashSet::new();
+    let mut input = String::new();
+
+    while hands.len() < size {
+        let count = rng.gen_range(1..=5);
+        let kinds: Vec<char> = CARDS.choose_multiple(rng, count).copied().collect();
+        let hand: String = (0..5).map(|_| *kinds.choose(rng).unwrap()).collect();
+
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=1000)));
+        }
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,295 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+use aoc_core::{parse, Answer, ParseError};
+
+pub mod generate;
+
+// Entrypoint
+pub fn parse(input: &str) -> Result<HandList, ParseError> {
+    let mut hand_list: HandList = HandList::new();
+
+    for line in input.lines() {
+        hand_list.add_hand(line.parse()?)
+    }
+
+    Ok(hand_list)
+}
+
+pub fn render(hand_list: &HandList) -> String {
+    hand_list.hands.iter().map(|hand| format!("{}\n", hand)).collect()
+}
+
+pub fn part1(hand_list: &HandList) -> Answer {
+    hand_list.calculate_winnings(Rules::Standard).into()
+}
+
+pub fn part2(hand_list: &HandList) -> Answer {
+    hand_list.calculate_winnings(Rules::Jokers).into()
+}
+
+// ---------------------------------------------------------------------------------------------
+
+// Rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Rules {
+    Standard,
+    // J cards are jokers: the weakest card on their own, but they act like whatever card makes
+    // the strongest hand
+    Jokers,
+}
+// ---------------------------------------------------------------------------------------------
+
+// Card
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two
+}
+
+impl Card {
+    fn strength(&self, rules: Rules) -> usize {
+        match self {
+            Card::Ace => 14,
+            Card::King => 13,
+            Card::Queen => 12,
+            Card::Jack if rules == Rules::Jokers => 1,
+            Card::Jack => 11,
+            Card::Ten => 10,
+            Card::Nine => 9,
+            Card::Eight => 8,
+            Card::Seven => 7,
+            Card::Six => 6,
+            Card::Five => 5,
+            Card::Four => 4,
+            Card::Three => 3,
+            Card::Two => 2
+        }
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let card = match c {
+            'A' => Card::Ace,
+            'K' => Card::King,
+            'Q' => Card::Queen,
+            'J' => Card::Jack,
+            'T' => Card::Ten,
+            '9' => Card::Nine,
+            '8' => Card::Eight,
+            '7' => Card::Seven,
+            '6' => Card::Six,
+            '5' => Card::Five,
+            '4' => Card::Four,
+            '3' => Card::Three,
+            '2' => Card::Two,
+            _ => return Err(parse::unexpected(c))
+        };
+        Ok(card)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Card::Ace => 'A',
+            Card::King => 'K',
+            Card::Queen => 'Q',
+            Card::Jack => 'J',
+            Card::Ten => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2'
+        };
+        write!(f, "{}", c)
+    }
+}
+// ---------------------------------------------------------------------------------------------
+
+// HandType
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind
+}
+
+impl HandType {
+    fn new(cards: &[Card], rules: Rules) -> Self {
+        let mut hand_map: HashMap<&Card, usize> = cards.iter()
+            .fold(HashMap::new(), |mut acc, card| {
+                {
+                    acc.entry(card).and_modify(|e| *e += 1).or_insert(1);
+                }
+                acc
+            });
+
+        let number_of_jokers: usize = match rules {
+            Rules::Standard => 0,
+            Rules::Jokers => hand_map.remove(&Card::Jack).unwrap_or(0),
+        };
+
+        let hand_state: Vec<(&usize, usize)> = hand_map.values()
+            .fold(HashMap::<&usize, usize>::new(), |mut acc, count| {
+                {
+                    acc.entry(count).and_modify(|e| *e += 1).or_insert(1);
+                }
+                acc
+            })
+            .into_iter()
+            .collect();
+
+        match number_of_jokers {
+            0  => {
+                if hand_state.contains(&(&5, 1)) {
+                    Self::FiveOfAKind
+                } else if hand_state.contains(&(&4, 1)) {
+                    Self::FourOfAKind
+                } else if hand_state.contains(&(&3, 1)) && hand_state.contains(&(&2, 1)) {
+                    Self::FullHouse
+                } else if hand_state.contains(&(&3, 1)) {
+                    Self::ThreeOfAKind
+                } else if hand_state.contains(&(&2, 2)) {
+                    Self::TwoPair
+                } else if hand_state.contains(&(&2, 1)) {
+                    Self::OnePair
+                } else {
+                    Self::HighCard
+                }
+            },
+            1 => {
+                if hand_state.contains(&(&4, 1)) {
+                    Self::FiveOfAKind
+                } else if hand_state.contains(&(&3, 1)) {
+                    Self::FourOfAKind
+                } else if hand_state.contains(&(&2, 2)) {
+                    Self::FullHouse
+                } else if hand_state.contains(&(&2, 1)) {
+                    Self::ThreeOfAKind
+                } else {
+                    Self::OnePair
+                }
+
+            },
+            2 => {
+                if hand_state.contains(&(&3, 1)) {
+                    Self::FiveOfAKind
+                } else if hand_state.contains(&(&2, 1)) {
+                    Self::FourOfAKind
+                } else {
+                    Self::ThreeOfAKind
+                }
+
+            },
+            3 => {
+                if hand_state.contains(&(&2, 1)) {
+                    Self::FiveOfAKind
+                } else {
+                    Self::FourOfAKind
+                }
+            },
+            _ => Self::FiveOfAKind
+        }
+    }
+}
+// ---------------------------------------------------------------------------------------------
+
+// Hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Hand {
+    cards: Vec<Card>,
+    bet: u64
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards, bet) = parse::split_once(s, " ")?;
+
+        let cards: Vec<Card> = cards
+            .char_indices()
+            .map(|(i, c)| Card::try_from(c).map_err(|e| e.with_location(&cards[i..])))
+            .collect::<Result<_, _>>()?;
+        if cards.len() != 5 {
+            return Err(ParseError::at(s, format!("expected 5 cards, found `{}`", s)));
+        }
+
+        Ok(Self {
+            cards,
+            bet: parse::number(bet)?
+        })
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in &self.cards {
+            write!(f, "{}", card)?;
+        }
+        write!(f, " {}", self.bet)
+    }
+}
+
+impl Hand {
+    // Hands are ranked by their type first, then card by card
+    fn strength(&self, rules: Rules) -> (HandType, Vec<usize>) {
+        (
+            HandType::new(&self.cards, rules),
+            self.cards.iter().map(|card| card.strength(rules)).collect(),
+        )
+    }
+}
+// ---------------------------------------------------------------------------------------------
+
+// HandList
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandList {
+    hands: Vec<Hand>
+}
+
+impl HandList {
+    fn new() -> Self {
+        Self {
+            hands: vec![]
+        }
+    }
+
+    fn add_hand(&mut self, hand: Hand) {
+        self.hands.push(hand);
+    }
+
+    fn calculate_winnings(&self, rules: Rules) -> u64 {
+        let mut hands = self.hands.clone();
+        hands.sort_by_cached_key(|hand| hand.strength(rules));
+
+        hands.iter().zip(1_u64..).map(|(hand, rank)| hand.bet * rank).sum()
+    }
+}
+// ---------------------------------------------------------------------------------------------
@@ -0,0 +1,59 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::{generate, Generated};
+
+const PRIMES: [u64; 25] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+// About `size` nodes (12000 at most) laid out like in the puzzle: every `..A` node starts a loop of
+// its own that goes through its `..Z` node every `instructions × prime` steps, `AAA` and `ZZZ`
+// being one of them. The steps of both parts are known.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let size = size.clamp(2, 12000) as u64;
+    let ghosts = (size / 100).clamp(1, 6);
+    let instructions: String = (0..rng.gen_range(1..=(size / ghosts / 2).clamp(1, 300)))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let length = instructions.len() as u64;
+
+    let fitting: Vec<u64> = PRIMES.iter().copied().filter(|&p| p <= (size / ghosts / length).max(2)).collect();
+    let primes: Vec<u64> = fitting.choose_multiple(rng, ghosts as usize).copied().collect();
+
+    let loops: Vec<u64> = primes.iter().map(|p| p * length).collect();
+    let mut names = generate::names(rng, loops.iter().sum::<u64>() as usize, 3, |name| !name.ends_with(['a', 'z'])).into_iter();
+    let prefixes = generate::names(rng, primes.len() - 1, 2, |name| name != "aa" && name != "zz");
+
+    let mut nodes: Vec<String> = vec![];
+
+    for (ghost, &steps) in loops.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (format!("{}A", prefixes[ghost - 1]), format!("{}Z", prefixes[ghost - 1])),
+        };
+
+        // `path[k]` is reached after `k` steps, the instructions the ghost reads there are always
+        // the same so the other branch may lead anywhere
+        let mut path: Vec<String> = vec![start];
+        path.extend(names.by_ref().take(steps as usize - 1).map(|name| name.to_ascii_uppercase()));
+        path.push(end);
+
+        let mut node = |name: &str, k: usize, next: &str| {
+            let decoy = &path[rng.gen_range(1..path.len() - 1)];
+            let (left, right) = match instructions.as_bytes()[k % instructions.len()] {
+                b'L' => (next, decoy.as_str()),
+                _ => (decoy.as_str(), next),
+            };
+            nodes.push(format!("{} = ({}, {})", name, left, right));
+        };
+
+        for k in 0..path.len() - 1 {
+            node(&path[k], k, &path[k + 1]);
+        }
+        // The `..Z` node reads the first instruction again, and goes on like its `..A` node
+        node(&path[path.len() - 1], 0, &path[1]);
+    }
+
+    nodes.shuffle(rng);
+
+    Generated::new(format!("{}\n\n{}\n", instructions, nodes.join("\n")))
+        .with_answer(1, loops[0])
+        .with_answer(2, length * primes.iter().product::<u64>())
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::LazyLock};
+use num_bigint::BigUint;
+use regex::Regex;
+use rayon::prelude::*;
+use aoc_core::{cycle, math, parse, Answer, ParseError};
+
+pub mod generate;
+
+static NODE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?<current>\w{3}) = \((?<left>\w{3}), (?<right>\w{3})\)$").unwrap());
+
+pub fn parse(input: &str) -> Result<(Vec<char>, Map), ParseError> {
+    let (instructions, nodes) = parse::split_once(input, "\n\n")?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::at(instructions, "expected at least one instruction"));
+    }
+    if let Some((i, c)) = instructions.char_indices().find(|(_, c)| *c != 'L' && *c != 'R') {
+        return Err(parse::unexpected(c).with_location(&instructions[i..]));
+    }
+    let instructions: Vec<char> = instructions.chars().collect();
+    let mut map: Map = Map::new();
+
+    let mut references: Vec<&str> = vec![];
+    for node_desc in nodes.split('\n').filter(|r| !r.is_empty()) {
+        let captures = NODE
+            .captures(node_desc)
+            .ok_or_else(|| ParseError::at(node_desc, "expected `<node> = (<left>, <right>)`"))?;
+
+        let node: Node = Node::new(&captures["current"], &captures["left"], &captures["right"]);
+        map.add_node(node);
+        references.extend([captures.name("left").unwrap().as_str(), captures.name("right").unwrap().as_str()]);
+    }
+
+    if let Some(unknown) = references.into_iter().find(|r| !map.map.contains_key(*r)) {
+        return Err(ParseError::at(unknown, format!("unknown node `{}`", unknown)));
+    }
+
+    Ok((instructions, map))
+}
+
+pub fn render((instructions, map): &(Vec<char>, Map)) -> String {
+    let mut nodes: Vec<&Node> = map.map.values().collect();
+    nodes.sort_by_key(|node| &node.current);
+
+    nodes.iter().fold(instructions.iter().collect::<String>() + "\n\n", |input, node| {
+        input + &format!("{} = ({}, {})\n", node.current, node.left, node.right)
+    })
+}
+
+pub fn part1((instructions, map): &(Vec<char>, Map)) -> Result<Answer, ParseError> {
+    for node in ["AAA", "ZZZ"] {
+        if !map.map.contains_key(node) {
+            return Err(ParseError::new(format!("expected a `{}` node", node)));
+        }
+    }
+
+    map.navigate("AAA", "ZZZ", instructions)
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::new("`ZZZ` cannot be reached from `AAA`"))
+}
+
+// The ghosts go round in circles, at the steps on a node ending with `Z` in the cycle of each of
+// them the number of steps is the same modulo the length of its cycle
+pub fn part2((instructions, map): &(Vec<char>, Map)) -> Result<Answer, ParseError> {
+    map.navigate_ghosts(instructions).map(Answer::from)
+}
+
+// The cycle every ghost ends up in, by starting node
+#[cfg(feature = "serde")]
+pub fn solved((instructions, map): &(Vec<char>, Map)) -> impl serde::Serialize + '_ {
+    map.get_nodes_ending_with_a()
+        .into_iter()
+        .map(|node| (node, map.get_cycle(node, instructions)))
+        .collect::<std::collections::BTreeMap<&str, Cycle>>()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    current: String,
+    left: String,
+    right: String
+}
+
+impl Node {
+    fn new(current: &str, left: &str, right: &str) -> Self {
+        Self {
+            current: current.to_string(),
+            left: left.to_string(),
+            right: right.to_string()
+        }
+    }
+
+    fn get_next(&self, instruction: &char) -> &str {
+        match instruction {
+            'L' => self.left.as_str(),
+            'R' => self.right.as_str(),
+            _ => unreachable!()
+        }
+    }
+}
+
+// The steps before a ghost goes round in circles, how long it takes to, and the steps on the way at
+// which it stands on a node ending with `Z`
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Cycle {
+    start: usize,
+    length: usize,
+    relevant_nodes: HashMap<String, usize>
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map {
+    map: HashMap<String, Node>
+}
+
+impl Map {
+    fn new() -> Self {
+        Self { map: HashMap::new() }
+    }
+
+    fn add_node(&mut self, node: Node) {
+        self.map.insert(node.current.clone(), node);
+    }
+
+    // None when the walk goes round in circles first, which it does once it has been through every
+    // node at every instruction
+    fn navigate(&self, start: &str, end: &str, instructions: &[char]) -> Option<u64> {
+        let mut state: (&str, usize) = (start, 0);
+
+        for count in 0..=self.map.len() * instructions.len() {
+            if state.0 == end {
+                return Some(count as u64);
+            }
+            state = self.step(&state, instructions);
+        }
+
+        None
+    }
+
+    fn get_nodes_ending_with_a(&self) -> Vec<&str> {
+        self.map.keys().filter(|n| n.ends_with('A')).map(|n| n.as_str()).collect()
+    }
+
+    // Where a ghost is, and the instruction it follows next
+    fn step<'a>(&'a self, (current, instructions_index): &(&'a str, usize), instructions: &[char]) -> (&'a str, usize) {
+        let next = self.map[*current].get_next(&instructions[*instructions_index]);
+
+        (next, (instructions_index + 1) % instructions.len())
+    }
+
+    #[cfg(feature = "serde")]
+    fn get_cycle(&self, start: &str, instructions: &[char]) -> Cycle {
+        let run = cycle::run((start, 0), |state| self.step(state, instructions));
+
+        Cycle {
+            start: run.cycle.prefix,
+            length: run.cycle.period,
+            relevant_nodes: run.states
+                .iter()
+                .enumerate()
+                .skip(run.cycle.prefix)
+                .filter(|(_, (node, _))| node.ends_with('Z'))
+                .map(|(count, (node, _))| (node.to_string(), count))
+                .collect(),
+        }
+    }
+
+    // The ghosts may only meet after more steps than fit in a u64
+    fn navigate_ghosts(&self, instructions: &[char]) -> Result<BigUint, ParseError> {
+        let mut current_nodes: Vec<&str> = self.get_nodes_ending_with_a();
+        if current_nodes.is_empty() {
+            return Err(ParseError::new("expected nodes ending with `A`"));
+        }
+        current_nodes.sort_unstable();
+
+        let runs: Vec<cycle::Run<(&str, usize)>> = current_nodes
+            .into_par_iter()
+            .map(|node| cycle::run((node, 0), |state| self.step(state, instructions)))
+            .collect();
+        let on_z = |run: &cycle::Run<(&str, usize)>, step: usize| run.state_at(step).0.ends_with('Z');
+
+        // Before every ghost is in its cycle
+        let settled = runs.iter().map(|run| run.cycle.prefix).max().unwrap_or(0);
+        if let Some(step) = (0..settled).find(|&step| runs.iter().all(|run| on_z(run, step))) {
+            return Ok(step.into());
+        }
+
+        // Then the ghosts may be on any of the `Z` nodes of their cycle, each choice of them is tried
+        let choices: Vec<Vec<(i64, u64)>> = runs
+            .iter()
+            .map(|run| {
+                (run.cycle.prefix..run.cycle.prefix + run.cycle.period)
+                    .filter(|&step| on_z(run, step))
+                    .map(|step| (step as i64, run.cycle.period as u64))
+                    .collect()
+            })
+            .collect();
+        let combinations: Vec<Vec<(i64, u64)>> = choices.iter().fold(vec![vec![]], |combinations, choice| {
+            combinations
+                .iter()
+                .flat_map(|combination| choice.iter().map(move |congruence| [combination.as_slice(), &[*congruence]].concat()))
+                .collect()
+        });
+
+        combinations
+            .into_iter()
+            .filter_map(math::big_crt)
+            .map(|(step, period)| {
+                // The steps before the cycles start do not come back
+                let settled = BigUint::from(settled);
+                if step >= settled {
+                    step
+                } else {
+                    let skipped = (&settled - &step + &period - 1_u8) / &period;
+                    step + skipped * period
+                }
+            })
+            .min()
+            .ok_or_else(|| ParseError::new("the ghosts never all stand on nodes ending with `Z` at once"))
+    }
+}
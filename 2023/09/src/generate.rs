@@ -0,0 +1,23 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// `size` histories of 21 values of integer polynomials of degree 4 at most, whose next and
+// previous values are known
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut next, mut previous): (i64, i64) = (0, 0);
+
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.gen_range(0..=4)).map(|_| rng.gen_range(-3..=3)).collect();
+        let value = |x: i64| coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+
+        let history: Vec<String> = (0..21).map(|x| value(x).to_string()).collect();
+        input.push_str(&history.join(" "));
+        input.push('\n');
+
+        next += value(21);
+        previous += value(-1);
+    }
+
+    Generated::new(input).with_answer(1, next).with_answer(2, previous)
+}
@@ -0,0 +1,61 @@
+use std::{fmt, str::FromStr};
+use aoc_core::{math, parse, Answer, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<ValueHistory>, ParseError> {
+    input.lines().map(str::parse).collect()
+}
+
+pub fn render(input: &[ValueHistory]) -> String {
+    input.iter().map(|history| format!("{}\n", history)).collect()
+}
+
+pub fn part1(input: &[ValueHistory]) -> Result<Answer, ParseError> {
+    predictions_sum(input, |history| history.history.len() as i64)
+}
+
+pub fn part2(input: &[ValueHistory]) -> Result<Answer, ParseError> {
+    predictions_sum(input, |_| -1)
+}
+
+// Predicts the value at the position `at` gives, the first value being at 0
+fn predictions_sum(input: &[ValueHistory], at: fn(&ValueHistory) -> i64) -> Result<Answer, ParseError> {
+    input
+        .iter()
+        .try_fold(0_i64, |sum, history| sum.checked_add(history.predict(at(history))?))
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::new("the predictions do not fit in an i64"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValueHistory {
+    history: Vec<i32>
+}
+
+impl FromStr for ValueHistory {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            history: s.split(' ').map(parse::number).collect::<Result<_, _>>()?
+        })
+    }
+}
+
+impl fmt::Display for ValueHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self.history.iter().map(i32::to_string).collect();
+        write!(f, "{}", values.join(" "))
+    }
+}
+
+// The values are those of a polynomial, the one going through all of them makes the predictions
+impl ValueHistory {
+    fn predict(&self, x: i64) -> Option<i64> {
+        let values: Vec<i64> = self.history.iter().copied().map(i64::from).collect();
+
+        math::extrapolate(&values, x)
+    }
+}
@@ -0,0 +1,61 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::{generate, Direction, Generated, Grid, Position};
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+// A `2 × size + 1` square field of pipes, the loop goes around a random shape grown on a `size` ×
+// `size` grid at twice its scale, so that it never runs next to itself and encloses tiles. Both
+// answers are known: the loop is `2 × boundary` tiles long and, by Pick's theorem, encloses
+// `area - boundary / 2 + 1` tiles with `area` four times the cells of the shape.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let shape = generate::polyomino(rng, side, side * side / 2);
+    let cells = shape.iter().filter(|(_, &filled)| filled).count();
+    let corners = generate::boundary(&shape);
+
+    let mut path: Vec<Position> = vec![];
+    for (corner, next) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+        path.push(Position::new(2 * corner.row, 2 * corner.col));
+        path.push(Position::new(corner.row + next.row, corner.col + next.col));
+    }
+
+    let mut field = Grid::new(2 * side + 1, 2 * side + 1, '.');
+    for position in field.positions().collect::<Vec<Position>>() {
+        field[position] = *JUNK.choose(rng).unwrap();
+    }
+
+    for (i, &position) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+
+        field[position] = match (towards(position, previous), towards(position, next)) {
+            (Direction::Up, Direction::Down) | (Direction::Down, Direction::Up) => '|',
+            (Direction::Left, Direction::Right) | (Direction::Right, Direction::Left) => '-',
+            (Direction::Up, Direction::Right) | (Direction::Right, Direction::Up) => 'L',
+            (Direction::Up, Direction::Left) | (Direction::Left, Direction::Up) => 'J',
+            (Direction::Down, Direction::Left) | (Direction::Left, Direction::Down) => '7',
+            _ => 'F',
+        };
+    }
+
+    // Only the loop may connect to the start
+    let start = *path.choose(rng).unwrap();
+    let around: Vec<Position> = field.neighbours4(start).filter(|neighbour| !path.contains(neighbour)).collect();
+    for neighbour in around {
+        field[neighbour] = '.';
+    }
+    field[start] = 'S';
+
+    Generated::new(field.to_string())
+        .with_answer(1, corners.len())
+        .with_answer(2, 4 * cells + 1 - corners.len())
+}
+
+fn towards(from: Position, to: Position) -> Direction {
+    match (to.row.cmp(&from.row), to.col.cmp(&from.col)) {
+        (std::cmp::Ordering::Less, _) => Direction::Up,
+        (std::cmp::Ordering::Greater, _) => Direction::Down,
+        (_, std::cmp::Ordering::Less) => Direction::Left,
+        _ => Direction::Right,
+    }
+}
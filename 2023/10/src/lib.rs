@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use aoc_core::{parse, Answer, Frame, Grid, ParseError, Position};
+use aoc_core::visual::{Cell, Color};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<PipeMap, ParseError> {
+    input.parse()
+}
+
+pub fn render(pipe_map: &PipeMap) -> String {
+    pipe_map.map.map(Node::symbol).to_string()
+}
+
+pub fn part1(pipe_map: &PipeMap) -> Answer {
+    pipe_map.get_number_of_steps_to_farthest_node().into()
+}
+
+pub fn part2(pipe_map: &PipeMap) -> Answer {
+    pipe_map.get_enclosed_tiles().len().into()
+}
+
+// The loop stands out of the other pipes, part 2 also shows the tiles it encloses
+pub fn frames(pipe_map: &PipeMap, part: u8) -> Vec<Frame> {
+    let pipe_loop: HashMap<Position, &Node> = pipe_map.get_loop();
+    let enclosed: HashSet<Position> = if part == 1 { HashSet::new() } else { pipe_map.get_enclosed_tiles().into_iter().collect() };
+
+    let frame = Frame::from_grid(&pipe_map.map, |position, node| {
+        let glyph = node.to_string().chars().next().unwrap_or(' ');
+
+        if node == &Node::Start {
+            Cell::new(glyph, Color::RED)
+        } else if pipe_loop.contains_key(&position) {
+            Cell::new(glyph, Color::YELLOW)
+        } else if enclosed.contains(&position) {
+            Cell::new('I', Color::GREEN)
+        } else {
+            Cell::new(glyph, Color::DIM)
+        }
+    });
+
+    let caption = match part {
+        1 => format!("the farthest tile is {} steps away", pipe_map.get_number_of_steps_to_farthest_node()),
+        _ => format!("{} enclosed tiles", enclosed.len()),
+    };
+
+    vec![frame.with_caption(caption)]
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Node {
+    NorthSouthPipe, // │
+    EastWestPipe,   // ─
+
+    NorthEastPipe,  // └
+    NorthWestPipe,  // ┘
+
+    SouthEastPipe,  // ┌
+    SouthWestPipe,  // ┐
+
+    Ground,
+    Start,
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string_to_write = match self {
+            Node::NorthSouthPipe => "│",
+            Node::EastWestPipe => "─",
+            Node::NorthEastPipe => "└",
+            Node::NorthWestPipe => "┘",
+            Node::SouthEastPipe => "┌",
+            Node::SouthWestPipe => "┐",
+            Node::Ground => ".",
+            Node::Start => "S"
+        };
+        write!(f, "{}", string_to_write)
+    }
+}
+
+impl TryFrom<char> for Node {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let node = match c {
+            '|' => Node::NorthSouthPipe,
+            '-' => Node::EastWestPipe,
+            'L' => Node::NorthEastPipe,
+            'J' => Node::NorthWestPipe,
+            'F' => Node::SouthEastPipe,
+            '7' => Node::SouthWestPipe,
+            '.' => Node::Ground,
+            'S' => Node::Start,
+            _ => return Err(parse::unexpected(c))
+        };
+        Ok(node)
+    }
+}
+
+impl Node {
+    // The character of the puzzle input, where `Display` draws the pipes
+    fn symbol(&self) -> char {
+        match self {
+            Node::NorthSouthPipe => '|',
+            Node::EastWestPipe => '-',
+            Node::NorthEastPipe => 'L',
+            Node::NorthWestPipe => 'J',
+            Node::SouthEastPipe => 'F',
+            Node::SouthWestPipe => '7',
+            Node::Ground => '.',
+            Node::Start => 'S'
+        }
+    }
+
+    fn can_connect(&self, direction: Direction) -> bool {
+        match (self, direction) {
+            (Node::NorthSouthPipe, Direction::North) => true,
+            (Node::NorthSouthPipe, Direction::South) => true,
+            (Node::EastWestPipe, Direction::East) => true,
+            (Node::EastWestPipe, Direction::West) => true,
+            (Node::NorthEastPipe, Direction::North) => true,
+            (Node::NorthEastPipe, Direction::East) => true,
+            (Node::NorthWestPipe, Direction::North) => true,
+            (Node::NorthWestPipe, Direction::West) => true,
+            (Node::SouthEastPipe, Direction::South) => true,
+            (Node::SouthEastPipe, Direction::East) => true,
+            (Node::SouthWestPipe, Direction::South) => true,
+            (Node::SouthWestPipe, Direction::West) => true,
+            (Node::Start, _) => true,
+            (Node::Ground, _) => false,
+            _ => false
+        }
+    }
+
+    fn get_next_step(&self, from: &Direction) -> (isize, isize, Direction) {
+        match (self, from) {
+            (Node::NorthSouthPipe, Direction::North) => (1, 0, Direction::North),
+            (Node::NorthSouthPipe, Direction::South) => (-1, 0, Direction::South),
+            (Node::EastWestPipe, Direction::East) => (0, -1, Direction::East),
+            (Node::EastWestPipe, Direction::West) => (0, 1, Direction::West),
+            (Node::NorthEastPipe, Direction::North) => (0, 1, Direction::West),
+            (Node::NorthEastPipe, Direction::East) => (-1, 0, Direction::South),
+            (Node::NorthWestPipe, Direction::North) => (0, -1, Direction::East),
+            (Node::NorthWestPipe, Direction::West) => (-1, 0, Direction::South),
+            (Node::SouthEastPipe, Direction::South) => (0, 1, Direction::West),
+            (Node::SouthEastPipe, Direction::East) => (1, 0, Direction::North),
+            (Node::SouthWestPipe, Direction::South) => (0, -1, Direction::East),
+            (Node::SouthWestPipe, Direction::West) => (1, 0, Direction::North),
+            _ => unreachable!()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PipeMap {
+    map: Grid<Node>,
+    start: Position,
+    start_node: Node,
+}
+
+impl fmt::Display for PipeMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.map)
+    }
+}
+
+impl FromStr for PipeMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<Node> = s.parse()?;
+
+        let start = map
+            .find(|node| node == &Node::Start)
+            .ok_or_else(|| ParseError::at(s, "expected a start tile `S`"))?;
+        if map.iter().filter(|(_, node)| *node == &Node::Start).count() > 1 {
+            return Err(ParseError::at(s, "expected a single start tile `S`"));
+        }
+
+        // The start tile takes the shape that connects it to its two neighbours
+        let connects = |direction: aoc_core::Direction, to: Direction| {
+            map.step(start, direction, 1).is_some_and(|position| map[position].can_connect(to))
+        };
+        let west_node = connects(aoc_core::Direction::Left, Direction::East);
+        let east_node = connects(aoc_core::Direction::Right, Direction::West);
+        let north_node = connects(aoc_core::Direction::Up, Direction::South);
+        let south_node = connects(aoc_core::Direction::Down, Direction::North);
+
+        let start_node: Node = match (west_node, east_node, north_node, south_node) {
+            (true, true, false, false) => Node::EastWestPipe,
+            (true, false, true, false) => Node::NorthWestPipe,
+            (true, false, false, true) => Node::SouthWestPipe,
+            (false, true, true, false) => Node::NorthEastPipe,
+            (false, true, false, true) => Node::SouthEastPipe,
+            (false, false, true, true) => Node::NorthSouthPipe,
+            _ => {
+                let start_tile = &s.lines().nth(start.row).unwrap()[start.col..];
+                return Err(ParseError::at(start_tile, "expected the start tile to connect to exactly two pipes"));
+            }
+        };
+
+        Ok(Self {
+            map,
+            start,
+            start_node,
+        })
+    }
+}
+
+impl PipeMap {
+    fn walk(&self, start: Position, from: &Direction) -> (Position, Direction) {
+        let next_step = match &self.map[start] {
+            Node::Ground => unreachable!(),
+            Node::Start => self.start_node.get_next_step(from),
+            node => node.get_next_step(from)
+        };
+        (Position::new(start.row.wrapping_add_signed(next_step.0), start.col.wrapping_add_signed(next_step.1)), next_step.2)
+    }
+
+    fn get_loop(&self) -> HashMap<Position, &Node> {
+        let mut position = self.start;
+        let mut from = match &self.start_node {
+            Node::NorthSouthPipe => Direction::North,
+            Node::EastWestPipe => Direction::West,
+            Node::NorthEastPipe => Direction::North,
+            Node::NorthWestPipe => Direction::North,
+            Node::SouthEastPipe => Direction::South,
+            Node::SouthWestPipe => Direction::South,
+            _ => unreachable!()
+        };
+
+        let mut visited: HashMap<Position, &Node> = HashMap::from([
+            (position, &self.map[position])
+        ]);
+
+        loop {
+            (position, from) = self.walk(position, &from);
+
+            if let std::collections::hash_map::Entry::Vacant(e) = visited.entry(position) {
+                e.insert(&self.map[position]);
+            } else {
+                break;
+            }
+        }
+        visited
+    }
+
+    fn get_number_of_steps_to_farthest_node(&self) -> usize {
+        self.get_loop().len().div_ceil(2)
+    }
+
+    fn get_enclosed_tiles(&self) -> Vec<Position> {
+        let mut enclosed: Vec<Position> = vec![];
+
+        let pipe_loop: HashMap<Position, &Node> = self.get_loop();
+
+        for (row_index, row) in self.map.iter_rows().enumerate() {
+            let mut is_enclosed = false;
+            let mut last_bend: Option<Node> = None;
+
+            for (col_index, _) in row.iter().enumerate() {
+                if let Some(node) = pipe_loop.get(&Position::new(row_index, col_index)) {
+                    let node = if node != &&Node::Start { **node } else { self.start_node };
+
+                    if node == Node::NorthSouthPipe ||
+                       node == Node::SouthWestPipe && last_bend == Some(Node::NorthEastPipe) ||
+                       node == Node::NorthWestPipe && last_bend == Some(Node::SouthEastPipe) {
+                        is_enclosed = !is_enclosed;
+                    }
+
+                    if [Node::NorthEastPipe, Node::NorthWestPipe, Node::SouthEastPipe, Node::SouthWestPipe].contains(&node) {
+                        last_bend = Some(node);
+                    }
+
+                    continue;
+                }
+                if is_enclosed {
+                    enclosed.push(Position::new(row_index, col_index));
+                }
+            }
+        }
+
+        enclosed
+    }
+}
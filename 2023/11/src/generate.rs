@@ -0,0 +1,20 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// A `size` × `size` image where about one tile in 25 is a galaxy, some rows and columns are left
+// empty to be expanded
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let empty_rows: Vec<bool> = (0..side).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..side).map(|_| rng.gen_bool(0.1)).collect();
+    let mut input = String::new();
+
+    for &empty_row in &empty_rows {
+        for &empty_col in &empty_cols {
+            input.push(if !empty_row && !empty_col && rng.gen_bool(0.04) { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
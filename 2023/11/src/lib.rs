@@ -0,0 +1,141 @@
+use std::{iter, str::FromStr};
+use aoc_core::{parse, Answer, Grid, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Image, ParseError> {
+    input.parse()
+}
+
+pub fn render(image: &Image) -> String {
+    image.contents.to_string()
+}
+
+pub fn part1(image: &Image) -> Answer {
+    let universe: Universe = image.expand(2).into();
+
+    universe.find_sum_of_distance_between_all_galaxies().into()
+}
+
+pub fn part2(image: &Image) -> Answer {
+    let universe: Universe = image.expand(1000000).into();
+
+    universe.find_sum_of_distance_between_all_galaxies().into()
+}
+
+// Image
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Image {
+    contents: Grid<char>,
+}
+
+impl FromStr for Image {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let contents: Grid<char> = Grid::parse_with(s, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(parse::unexpected(c)),
+        })?;
+
+        Ok(Self { contents })
+    }
+}
+
+impl Image {
+    // Every row and column without a galaxy is `factor` times as large
+    fn expand(&self, factor: u64) -> ExpandedInput {
+        let row_weights: Vec<u64> = self.contents.iter_rows().map(|row| {
+                if row.iter().any(|c| c == &'#') {
+                    1
+                } else {
+                    factor
+                }
+            })
+            .collect();
+
+        let col_weights: Vec<u64> = self.contents.iter_columns().map(|mut col| {
+            if col.any(|c| c == &'#') {
+                1
+            } else {
+                factor
+            }
+        })
+        .collect();
+
+        ExpandedInput(
+            self.contents
+                .iter_rows()
+                .zip(row_weights)
+                .map(|(r, rw)| {
+                    let row: Vec<(char, u64)> = r.iter().zip(col_weights.clone()).map(|(c, cw)| (*c, cw)).collect();
+                    (row, rw)
+                })
+                .collect()
+        )
+    }
+}
+
+// ExpandedInput
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ExpandedInput(Vec<(Vec<(char,  u64)>, u64)>);
+
+// Galaxy
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Galaxy {
+    x: u64,
+    y: u64
+}
+
+// Universe
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Universe {
+    galaxies: Vec<Galaxy>
+}
+
+impl From<ExpandedInput> for Universe {
+    fn from(e: ExpandedInput) -> Self {
+        let ExpandedInput(expanded_input) = e;
+        let mut x: u64 = 0;
+        let mut y: u64 = 0;
+
+        let mut galaxies: Vec<Galaxy> = vec![];
+
+        for row in expanded_input.iter() {
+            for col in row.0.iter() {
+                if col.0 == '#' {
+                    galaxies.push(Galaxy { x, y });
+                }
+                y += col.1;
+            }
+            y = 0;
+            x += row.1
+        }
+        Self { galaxies }
+    }
+}
+
+impl Universe {
+    fn get_all_galaxy_pairs(&self) -> Vec<(&Galaxy, &Galaxy)> {
+        self.galaxies.iter()
+            .enumerate()
+            .flat_map(|(i, g)| iter::repeat(g).zip(self.galaxies.iter().skip(i + 1))).collect()
+    }
+
+    // Summed in a u128, a large enough image expanded enough outgrows a u64
+    fn find_sum_of_distance_between_all_galaxies(&self) -> u128 {
+        self.get_all_galaxy_pairs()
+            .iter()
+            .fold(0, |acc, (g1, g2)| {
+                acc + u128::from(g1.x.abs_diff(g2.x) + g1.y.abs_diff(g2.y))
+            })
+    }
+}
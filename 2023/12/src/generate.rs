@@ -0,0 +1,27 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// `size` rows of up to 20 springs with at least one damaged spring, drawn first and then partly
+// hidden behind `?` so that every row has an arrangement
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let length = rng.gen_range(1..=20);
+        let mut springs: Vec<char> = (0..length).map(|_| if rng.gen_bool(0.45) { '#' } else { '.' }).collect();
+        if !springs.contains(&'#') {
+            springs[rng.gen_range(0..length)] = '#';
+        }
+
+        let groups: Vec<String> = springs
+            .split(|&spring| spring == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+
+        let row: String = springs.iter().map(|&spring| if rng.gen_bool(0.4) { '?' } else { spring }).collect();
+        input.push_str(&format!("{} {}\n", row, groups.join(",")));
+    }
+
+    Generated::new(input)
+}
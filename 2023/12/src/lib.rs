@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+use aoc_core::{memo::Memo, parse, Answer, ParseError};
+
+pub mod generate;
+
+// The springs of a row and the sizes of its groups of damaged springs
+pub type Row = (VecDeque<char>, VecDeque<usize>);
+
+pub fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (springs, sizes) = parse::split_once(line, " ")?;
+            if let Some((i, c)) = springs.char_indices().find(|(_, c)| !".#?".contains(*c)) {
+                return Err(parse::unexpected(c).with_location(&springs[i..]));
+            }
+
+            let row: VecDeque<char> = springs
+                .chars()
+                .collect();
+
+            let groups: VecDeque<usize> = sizes
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?;
+
+            Ok((row, groups))
+        })
+        .collect()
+}
+
+pub fn render(input: &[Row]) -> String {
+    input
+        .iter()
+        .map(|(row, groups)| {
+            let sizes: Vec<String> = groups.iter().map(usize::to_string).collect();
+            format!("{} {}\n", row.iter().collect::<String>(), sizes.join(","))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Row]) -> Answer {
+    arrangements_sum(input, 1)
+}
+
+// Every row is unfolded to five copies of itself joined by `?`
+pub fn part2(input: &[Row]) -> Answer {
+    arrangements_sum(input, 5)
+}
+
+fn arrangements_sum(input: &[Row], copies: usize) -> Answer {
+    let mut memo: Memo<(usize, usize, usize), u64> = Memo::new();
+    let mut sum: u64 = 0;
+
+    for (index, (row, groups)) in input.iter().enumerate() {
+        let row: String = row.iter().collect();
+        let springs: Vec<char> = vec![row; copies].join("?").chars().collect();
+        let groups: Vec<usize> = groups.iter().copied().cycle().take(groups.len() * copies).collect();
+
+        // The keys only tell the ends of the same row apart
+        memo.clear();
+        let arrangements = count_arrangements(&springs, &groups, 0, &mut memo);
+        tracing::debug!(row = index, arrangements, memoized = memo.len(), "row solved");
+
+        sum += arrangements;
+    }
+
+    let stats = memo.stats();
+    tracing::debug!(hits = stats.hits, misses = stats.misses, "arrangements counted");
+
+    sum.into()
+}
+
+// The arrangements of the springs left, with `current_group_size` damaged springs right before
+// them. A call is told apart by how many springs and groups are left.
+fn count_arrangements(springs: &[char], groups_left: &[usize], current_group_size: usize, memo: &mut Memo<(usize, usize, usize), u64>) -> u64 {
+    let Some(&group) = groups_left.first() else {
+        return u64::from(!springs.contains(&'#'));
+    };
+
+    memo.get_or_insert_with((springs.len(), groups_left.len(), current_group_size), |memo| {
+        let Some((&spring, springs)) = springs.split_first() else {
+            return u64::from(groups_left.len() == 1 && current_group_size == group);
+        };
+
+        let operational = |memo: &mut Memo<_, _>| {
+            if current_group_size == 0 {
+                count_arrangements(springs, groups_left, 0, memo)
+            } else if current_group_size == group {
+                count_arrangements(springs, &groups_left[1..], 0, memo)
+            } else {
+                0
+            }
+        };
+        let damaged = |memo: &mut Memo<_, _>| {
+            if current_group_size < group {
+                count_arrangements(springs, groups_left, current_group_size + 1, memo)
+            } else {
+                0
+            }
+        };
+
+        match spring {
+            '.' => operational(memo),
+            '#' => damaged(memo),
+            _ => operational(memo) + damaged(memo),
+        }
+    })
+}
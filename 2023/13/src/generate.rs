@@ -0,0 +1,43 @@
+use rand::Rng;
+use aoc_core::{Generated, Grid};
+
+// `size` patterns of 5 to 17 rows and columns, each with a perfect reflection line and another one
+// off by a single smudge: rows mirrored over the first lie outside of a span mirrored over the
+// second, where a tile is flipped
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let patterns: Vec<String> = (0..size).map(|_| pattern(rng).to_string()).collect();
+
+    Generated::new(patterns.join("\n"))
+}
+
+fn pattern<R: Rng>(rng: &mut R) -> Grid<char> {
+    let (rows, cols): (usize, usize) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+    let mut tiles: Vec<Vec<char>> = (0..rows)
+        .map(|_| (0..cols).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }).collect())
+        .collect();
+
+    // The smudged line falls between the columns `col - 1` and `col`
+    let col = rng.gen_range(1..cols);
+    for row in tiles.iter_mut() {
+        for left in (2 * col).saturating_sub(cols)..col {
+            row[2 * col - 1 - left] = row[left];
+        }
+    }
+
+    // The perfect line falls between the rows `row - 1` and `row`, the rows from `2 × row` are
+    // left out of it
+    let row = rng.gen_range(1..=(rows - 1) / 2);
+    for above in 0..row {
+        tiles[2 * row - 1 - above] = tiles[above].clone();
+    }
+
+    let smudge = (rng.gen_range(2 * row..rows), rng.gen_range((2 * col).saturating_sub(cols)..col));
+    tiles[smudge.0][smudge.1] = if tiles[smudge.0][smudge.1] == '#' { '.' } else { '#' };
+
+    if rng.gen_bool(0.5) {
+        tiles.reverse();
+    }
+    let pattern = Grid::from_rows(tiles).expect("the rows are as long");
+
+    if rng.gen_bool(0.5) { pattern.transpose() } else { pattern }
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+use aoc_core::{parse, Answer, Grid, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .trim_end()
+        .split("\n\n")
+        .map(str::parse)
+        .collect()
+}
+
+pub fn render(inputs: &[Pattern]) -> String {
+    inputs.iter().map(|pattern| pattern.rows.to_string()).collect::<Vec<String>>().join("\n")
+}
+
+pub fn part1(inputs: &[Pattern]) -> Result<Answer, ParseError> {
+    let mut sum: usize = 0;
+
+    for (index, input) in inputs.iter().enumerate() {
+        let point_summary = input
+            .find_reflection_point_summary(0)
+            .ok_or_else(|| no_reflection(inputs, index, "no line of reflection"))?;
+        tracing::trace!(pattern = index, summary = point_summary, "reflection line");
+        sum += point_summary;
+    }
+
+    Ok(sum.into())
+}
+
+// Every pattern has exactly one smudge, the reflection line is the one with a single difference
+pub fn part2(inputs: &[Pattern]) -> Result<Answer, ParseError> {
+    let mut sum: usize = 0;
+
+    for (index, input) in inputs.iter().enumerate() {
+        let point_summary = input
+            .find_reflection_point_summary(1)
+            .ok_or_else(|| no_reflection(inputs, index, "no line of reflection with a single smudge"))?;
+        tracing::trace!(pattern = index, summary = point_summary, "reflection line without the smudge");
+        sum += point_summary;
+    }
+
+    Ok(sum.into())
+}
+
+// Names the pattern by its number and its first line, the patterns are a blank line apart
+fn no_reflection(inputs: &[Pattern], index: usize, problem: &str) -> ParseError {
+    let line: usize = inputs[..index].iter().map(|pattern| pattern.rows.rows() + 1).sum::<usize>() + 1;
+
+    ParseError::new(format!("pattern {} (line {}) has {}", index + 1, line, problem))
+}
+
+fn find_reflection_point(pattern: &Grid<char>, known_smudges: usize) -> Option<usize> {
+    let number_of_rows: usize = pattern.rows();
+
+    for row_index in 0..number_of_rows {
+        if row_index == number_of_rows - 1 {
+            return None;
+        }
+
+        let limit = Ord::min(row_index, number_of_rows - row_index) + 1;
+        let mut errors: usize = 0;
+
+        for i in 0..limit {
+            if row_index + i + 1 < number_of_rows {
+                let left = pattern.row(row_index - i);
+                let right = pattern.row(row_index + i + 1);
+
+                errors += left.iter().zip(right).filter(|(l, r)| l != r).count();
+            }
+        }
+
+        if errors == known_smudges {
+            return Some(row_index + 1);
+        }
+    }
+    None
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    rows: Grid<char>
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Grid<char> = Grid::parse_with(s, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(parse::unexpected(c)),
+        })?;
+
+        Ok(Self { rows })
+    }
+}
+
+impl Pattern {
+    fn find_reflection_point_summary(&self, known_smudges: usize) -> Option<usize> {
+        match find_reflection_point(&self.rows, known_smudges) {
+            Some(row_reflection) => Some(100 * row_reflection),
+            None => find_reflection_point(&self.rows.transpose(), known_smudges),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// A `size` × `size` platform where a fifth of the tiles are round rocks and a tenth cube rocks
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(match rng.gen_range(0..10) {
+                0..=1 => 'O',
+                2 => '#',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+use std::fmt;
+use aoc_core::{cycle, parse, Answer, Frame, Grid, Parameters, Params, ParseError};
+use aoc_core::visual::{Cell, Color};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Grid<Node>, ParseError> {
+    input.parse()
+}
+
+pub fn render(input: &Grid<Node>) -> String {
+    input.to_string()
+}
+
+pub fn part1(input: &Grid<Node>) -> Answer {
+    let mut reflector: ParabolicReflector = ParabolicReflector::new(input.clone());
+
+    reflector.move_to(Direction::North);
+
+    reflector.north_load().into()
+}
+
+// The platform comes back to a state already seen long before the cycles run out
+pub fn part2(input: &Grid<Node>, config: Config) -> Answer {
+    let spins = cycle::run(ParabolicReflector::new(input.clone()), ParabolicReflector::spun);
+    tracing::debug!(start = spins.cycle.prefix, length = spins.cycle.period, "the platform repeats itself");
+
+    spins.state_at(config.cycles).north_load().into()
+}
+
+// Part 1 tilts the platform north, part 2 spins it until it comes back to a state already seen
+pub fn frames(input: &Grid<Node>, part: u8) -> Vec<Frame> {
+    let mut reflector: ParabolicReflector = ParabolicReflector::new(input.clone());
+    let mut frames: Vec<Frame> = vec![reflector.frame().with_caption(format!("load {}", reflector.north_load()))];
+
+    if part == 1 {
+        reflector.move_to(Direction::North);
+        frames.push(reflector.frame().with_caption(format!("tilted north, load {}", reflector.north_load())));
+        return frames;
+    }
+
+    let spins = cycle::run(reflector, ParabolicReflector::spun);
+    for (spin, reflector) in spins.states.iter().enumerate().skip(1) {
+        frames.push(reflector.frame().with_caption(format!("cycle {}, load {}", spin, reflector.north_load())));
+    }
+
+    let spin = spins.states.len();
+    let back = spins.state_at(spin);
+    frames.push(back.frame().with_caption(format!("cycle {}, load {}, as at cycle {}", spin, back.north_load(), spins.cycle.prefix)));
+
+    frames
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub cycles: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { cycles: 1_000_000_000 }
+    }
+}
+
+impl Parameters for Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        Ok(Self { cycles: params.take("cycles", Self::default().cycles)? })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Node {
+    Rock,
+    Fixed,
+    Empty
+}
+
+impl TryFrom<char> for Node {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Fixed),
+            'O' => Ok(Self::Rock),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "."),
+            Self::Fixed => write!(f, "#"),
+            Self::Rock => write!(f, "O"),
+        }
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Rock, Self::Empty) => Ordering::Less,
+            (Self::Empty, Self::Rock) => Ordering::Greater,
+            _ => Ordering::Equal
+        }
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SortKind {
+    Ascending,
+    Descending
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Direction {
+    North,
+    South,
+    East,
+    West
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ParabolicReflector {
+    nodes: Grid<Node>,
+}
+
+impl ParabolicReflector {
+    fn new(nodes: Grid<Node>) -> Self {
+        Self { nodes }
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.nodes, |_, node| match node {
+            Node::Empty => Cell::new('.', Color::DIM),
+            Node::Fixed => Cell::new('#', Color::WHITE),
+            Node::Rock => Cell::new('O', Color::YELLOW),
+        })
+    }
+
+    fn north_load(&self) -> usize {
+        let mut sum: usize = 0;
+
+        let input_len: usize = self.nodes.rows();
+        for (row_index, row) in self.nodes.iter_rows().enumerate() {
+            sum += row.iter().filter(|&node| node == &Node::Rock).count() * (input_len - row_index)
+        }
+
+        sum
+    }
+
+    // One spin cycle, tilting the platform north, west, south and then east
+    fn spun(&self) -> Self {
+        let mut reflector = self.clone();
+
+        reflector.move_to(Direction::North);
+        reflector.move_to(Direction::West);
+        reflector.move_to(Direction::South);
+        reflector.move_to(Direction::East);
+
+        tracing::trace!(load = reflector.north_load(), "spin cycle");
+        reflector
+    }
+
+    fn move_to(&mut self, direction: Direction) {
+        self.nodes = match &direction {
+                Direction::North => {
+                    ParabolicReflector::sort_nodes(&self.nodes.transpose(), SortKind::Ascending).transpose()
+                },
+                Direction::South => {
+                    ParabolicReflector::sort_nodes(&self.nodes.transpose(), SortKind::Descending).transpose()
+                },
+                Direction::East => {
+                    ParabolicReflector::sort_nodes(&self.nodes, SortKind::Descending)
+                },
+                Direction::West => {
+                    ParabolicReflector::sort_nodes(&self.nodes, SortKind::Ascending)
+                },
+            };
+    }
+
+    fn sort_nodes(nodes: &Grid<Node>, kind: SortKind) -> Grid<Node> {
+        let rows: Vec<Vec<Node>> = nodes
+            .iter_rows()
+            .map(|row| {
+                let splitted_row = row
+                    .split(|node| node == &Node::Fixed)
+                    .map(|node_slice| node_slice.to_vec())
+                    .collect::<Vec<Vec<Node>>>();
+
+                let mut moved_splits: Vec<Vec<Node>> = vec![];
+
+                for split in splitted_row {
+                    let mut moved_split = split.clone();
+
+                    match kind {
+                        SortKind::Ascending => moved_split.sort(),
+                        SortKind::Descending => moved_split.sort_by(|a, b| b.cmp(a))
+                    }
+
+                    moved_splits.push(moved_split);
+                }
+
+                let moved_rocks: Vec<Node> = moved_splits
+                    .join(&Node::Fixed);
+
+                moved_rocks
+            })
+            .collect();
+
+        Grid::from_rows(rows).expect("tilting keeps every row as long as it was")
+    }
+}
@@ -0,0 +1,18 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+// A line of `size` steps on about `size / 4` labels, lenses are put in more often than removed
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let labels: Vec<String> = (0..(size / 4).max(1))
+        .map(|_| (0..rng.gen_range(2..=6)).map(|_| rng.gen_range('a'..='z')).collect())
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) { format!("{}-", label) } else { format!("{}={}", label, rng.gen_range(1..=9)) }
+        })
+        .collect();
+
+    Generated::new(format!("{}\n", steps.join(",")))
+}
@@ -1,77 +1,108 @@
-use std::{fs, collections::HashMap};
+use std::{collections::HashMap, str::FromStr};
+use aoc_core::{parse, Answer, ParseError};
 
-fn main() {
-    let input: Vec<Step> = fs::read_to_string("input.txt").unwrap()
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
         .split(',')
+        .map(|step| step.trim_end_matches('\n'))
         .filter(|step| !step.is_empty())
-        .map(|step| step.strip_suffix('\n').unwrap_or(step).into())
-        .collect();
+        .map(str::parse)
+        .collect()
+}
+
+pub fn render(input: &[Step]) -> String {
+    input.iter().map(|step| step.raw.as_str()).collect::<Vec<&str>>().join(",") + "\n"
+}
 
+pub fn part1(input: &[Step]) -> Answer {
+    let result: u32 = input
+        .iter()
+        .map(|step| hash(&step.raw))
+        .sum();
+
+    result.into()
+}
+
+pub fn part2(input: &[Step]) -> Answer {
     let mut lava_facility: LavaFacility = LavaFacility {
         boxes: HashMap::new()
     };
 
     lava_facility.follow_steps(input);
 
-    // println!("Lava Facility: {:#?}", lava_facility);
+    lava_facility.calculate_focusing_power().into()
+}
 
-    println!("Result: {}", lava_facility.calculate_focusing_power());
+// The HASH algorithm, turns a string into a number in 0..256
+fn hash(s: &str) -> u32 {
+    s
+        .chars()
+        .fold(0, |acc, c| ((acc + c as u32) * 17) % 256)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Operation {
     Remove,
     Set
 }
 
-#[derive(Debug)]
-struct Step {
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Step {
+    raw: String,
     lense: Lense,
     operation: Operation,
     box_id: usize
 }
 
-impl From<&str> for Step {
-    fn from(s: &str) -> Self {
-        let (lense, operation) = if s.contains('=') {
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lense, operation) = if let Some((label, focal_length)) = s.split_once('=') {
             let operation: Operation = Operation::Set;
 
-            let splitted_step: Vec<&str> = s.split('=').collect();
             let lense = Lense {
-                label: splitted_step[0].to_string(),
-                focal_length: splitted_step[1].parse().unwrap()
+                label: label.to_string(),
+                focal_length: parse::number(focal_length)?
             };
 
             (lense, operation)
-        } else {
+        } else if let Some(label) = s.strip_suffix('-') {
             let operation: Operation = Operation::Remove;
             let lense = Lense {
-                label: s[..s.len() - 1].to_string(),
+                label: label.to_string(),
                 focal_length: 0
             };
 
             (lense, operation)
+        } else {
+            return Err(ParseError::at(s, format!("expected `<label>=<focal length>` or `<label>-`, found `{}`", s)));
         };
 
-        let box_id: usize = lense.label
-            .chars()
-            .fold(0, |acc, c| ((acc + c as u32) * 17) % 256) as usize;
+        let box_id: usize = hash(&lense.label) as usize;
 
-        Self {
+        Ok(Self {
+            raw: s.to_string(),
             lense,
             operation,
             box_id
-        }
+        })
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Lense {
     label: String,
     focal_length: usize
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Box {
     id: usize,
     lenses: Vec<Lense>,
@@ -79,19 +110,20 @@ struct Box {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct LavaFacility {
     boxes: HashMap<usize, Box>
 }
 
 impl LavaFacility {
-    fn follow_steps(&mut self, steps: Vec<Step>) {
+    fn follow_steps(&mut self, steps: &[Step]) {
         for step in steps {
             match step.operation {
                 Operation::Set => {
                     self.boxes
                         .entry(step.box_id)
                         .and_modify(|e| {
-                            if e.lense_map.get(&step.lense.label).is_some() {
+                            if e.lense_map.contains_key(&step.lense.label) {
                                 let index = e.lenses.iter().position(|lense| lense.label == step.lense.label).unwrap();
                                 e.lenses[index] = step.lense.clone();
                             } else {
@@ -102,7 +134,7 @@ impl LavaFacility {
                         .or_insert(Box {
                             id: step.box_id,
                             lenses: vec![step.lense.clone()],
-                            lense_map: HashMap::from([(step.lense.label.clone(), step.lense)])
+                            lense_map: HashMap::from([(step.lense.label.clone(), step.lense.clone())])
                         });
                 },
                 Operation::Remove => {
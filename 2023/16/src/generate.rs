@@ -0,0 +1,19 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+const DEVICES: [char; 4] = ['|', '-', '/', '\\'];
+
+// A `size` × `size` contraption where about one tile in 8 is a mirror or a splitter
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(if rng.gen_bool(0.125) { *DEVICES.choose(rng).unwrap() } else { '.' });
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
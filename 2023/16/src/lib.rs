@@ -0,0 +1,248 @@
+use std::{collections::HashMap, fmt};
+use aoc_core::{parse, Answer, Direction, Grid, ParseError, Position};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    input.parse()
+}
+
+pub fn render(tiles: &Grid<Tile>) -> String {
+    tiles.to_string()
+}
+
+pub fn part1(tiles: &Grid<Tile>) -> Answer {
+    let mut contraption: Contraption = tiles.into();
+
+    contraption.run_beam(vec![Beam::default()]);
+
+    contraption.energized_tiles_count.into()
+}
+
+pub fn part2(tiles: &Grid<Tile>) -> Answer {
+    let mut contraption: Contraption = tiles.into();
+
+    let row_limit = contraption.tiles.rows();
+    let col_limit = contraption.tiles.cols();
+
+    for row in 0..row_limit {
+        for col in 0..col_limit {
+            // Corners
+            // -----------------
+            if (row, col) == (0, 0) {
+                contraption.run_beam(vec![Beam::new(Position::new(0, 0), Direction::Left)]);
+                contraption.run_beam(vec![Beam::new(Position::new(0, 0), Direction::Up)]);
+            } else if (row, col) == (0, col_limit) {
+                contraption.run_beam(vec![Beam::new(Position::new(0, col_limit), Direction::Right)]);
+                contraption.run_beam(vec![Beam::new(Position::new(0, col_limit), Direction::Up)]);
+            } else if (row, col) == (row_limit, 0) {
+                contraption.run_beam(vec![Beam::new(Position::new(row_limit, 0), Direction::Left)]);
+                contraption.run_beam(vec![Beam::new(Position::new(row_limit, 0), Direction::Down)]);
+            } else if (row, col) == (row_limit, col_limit) {
+                contraption.run_beam(vec![Beam::new(Position::new(row_limit, col_limit), Direction::Right)]);
+                contraption.run_beam(vec![Beam::new(Position::new(row_limit, col_limit), Direction::Down)]);
+            }
+            // Sides
+            // -----------------
+            else if row == 0 {
+                contraption.run_beam(vec![Beam::new(Position::new(0, col), Direction::Up)])
+            } else if row == row_limit {
+                contraption.run_beam(vec![Beam::new(Position::new(row_limit, col), Direction::Down)])
+            } else if col == 0 {
+                contraption.run_beam(vec![Beam::new(Position::new(row, 0), Direction::Left)])
+            } else if col == col_limit {
+                contraption.run_beam(vec![Beam::new(Position::new(row, col_limit), Direction::Right)])
+            }
+            // Else
+            // -----------------
+            else {
+                continue;
+            }
+        }
+    }
+
+    contraption.energized_tiles_count.into()
+}
+
+// PassableByBeam Trait
+// -----------------------------------------------------------------------------
+trait PassableByBeam {
+    fn pass(&self, from: &Direction) -> Vec<Direction>;
+}
+
+// Splitter Enum
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Splitter {
+    Vertical,    // |
+    Horizontal,  // -
+}
+
+impl PassableByBeam for Splitter {
+    fn pass(&self, from: &Direction) -> Vec<Direction> {
+        match self {
+            Self::Vertical => {
+                if [Direction::Up, Direction::Down].contains(from) {
+                    vec![from.opposite()]
+                } else {
+                    vec![Direction::Up, Direction::Down]
+                }
+            },
+            Self:: Horizontal => {
+                if [Direction::Left, Direction::Right].contains(from) {
+                    vec![from.opposite()]
+                } else {
+                    vec![Direction::Left, Direction::Right]
+                }
+            }
+        }
+    }
+}
+
+// Mirror Enum
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mirror {
+    Left, // /
+    Right // \
+}
+
+impl PassableByBeam for Mirror {
+    fn pass(&self, from: &Direction) -> Vec<Direction> {
+        match self {
+            Self::Left => {
+                match from {
+                    Direction::Up => vec![Direction::Left],
+                    Direction::Right => vec![Direction::Down],
+                    Direction::Down => vec![Direction::Right],
+                    Direction::Left => vec![Direction::Up],
+                }
+            },
+            Self::Right => {
+                match from {
+                    Direction::Up => vec![Direction::Right],
+                    Direction::Right => vec![Direction::Up],
+                    Direction::Left => vec![Direction::Down],
+                    Direction::Down => vec![Direction::Left],
+                }
+            }
+        }
+    }
+}
+
+// Tile Enum
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tile {
+    Empty,     // .
+    Splitter(Splitter),
+    Mirror(Mirror),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '-' => Ok(Self::Splitter(Splitter::Horizontal)),
+            '|' => Ok(Self::Splitter(Splitter::Vertical)),
+            '/' => Ok(Self::Mirror(Mirror::Left)),
+            '\\' => Ok(Self::Mirror(Mirror::Right)),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "."),
+            Self::Splitter(Splitter::Horizontal) => write!(f, "-"),
+            Self::Splitter(Splitter::Vertical) => write!(f, "|"),
+            Self::Mirror(Mirror::Left) => write!(f, "/"),
+            Self::Mirror(Mirror::Right) => write!(f, "\\"),
+        }
+    }
+}
+
+impl PassableByBeam for Tile {
+    fn pass(&self, from: &Direction) -> Vec<Direction> {
+        match self {
+            Self::Empty => vec![from.opposite()],
+            Self::Splitter(splitter) => splitter.pass(from),
+            Self::Mirror(mirror) => mirror.pass(from),
+        }
+    }
+}
+
+// Beam Struct
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Beam {
+    position: Position,
+    from: Direction
+}
+
+impl Beam {
+    fn new(position: Position, from: Direction) -> Self {
+        Self { position, from }
+    }
+}
+
+impl Default for Beam {
+    fn default() -> Self {
+        Self::new(Position::new(0, 0), Direction::Left)
+    }
+}
+
+// Contraption Struct
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct Contraption<'a> {
+    tiles: &'a Grid<Tile>,
+    #[cfg_attr(feature = "serde", serde(with = "aoc_core::canonical::pairs"))]
+    energized_tiles_map: HashMap<Position, Vec<Direction>>,
+    energized_tiles_count: usize
+}
+
+impl<'a> From<&'a Grid<Tile>> for Contraption<'a> {
+    fn from(tiles: &'a Grid<Tile>) -> Self {
+        Self {
+            tiles,
+            energized_tiles_map: HashMap::new(),
+            energized_tiles_count: 0
+        }
+    }
+}
+
+impl Contraption<'_> {
+    fn run_beam(&mut self, mut beams: Vec<Beam>) {
+        self.energized_tiles_map = HashMap::new();
+
+        while let Some(beam) = beams.pop() {
+            if self.energized_tiles_map.get(&beam.position).is_some_and(|b| b.contains(&beam.from)) {
+                continue;
+            }
+
+            self.energized_tiles_map.entry(beam.position).and_modify(|e|  e.push(beam.from)).or_insert(vec![beam.from]);
+            for direction in self.tiles[beam.position].pass(&beam.from) {
+                if let Some(next_position) = self.tiles.step(beam.position, direction, 1) {
+                    beams.push(Beam::new(next_position, direction.opposite()));
+                }
+            }
+        }
+        if self.energized_tiles_count < self.count_energized_tiles() {
+            self.energized_tiles_count = self.count_energized_tiles();
+        }
+    }
+
+    fn count_energized_tiles(&self) -> usize {
+        self.energized_tiles_map.len()
+    }
+}
@@ -0,0 +1,17 @@
+use rand::Rng;
+use aoc_core::Generated;
+
+// A `size` × `size` map of heat losses from 1 to 9
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(char::from_digit(rng.gen_range(1..=9), 10).unwrap());
+        }
+        input.push('\n');
+    }
+
+    Generated::new(input)
+}
@@ -0,0 +1,237 @@
+use std::{collections::HashMap, fmt, ops::Range};
+use aoc_core::{parse, search, Answer, Direction, Frame, Grid, Parameters, Params, ParseError, Position};
+use aoc_core::visual::{Cell, Color};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Grid<Node>, ParseError> {
+    input.parse()
+}
+
+pub fn render(nodes: &Grid<Node>) -> String {
+    nodes.to_string()
+}
+
+pub fn part1(nodes: &Grid<Node>, config: Part1Config) -> Result<Answer, ParseError> {
+    least_heat_loss(nodes, config.min_straight..config.max_straight)
+}
+
+pub fn part2(nodes: &Grid<Node>, config: Part2Config) -> Result<Answer, ParseError> {
+    least_heat_loss(nodes, config.min_straight..config.max_straight)
+}
+
+fn least_heat_loss(nodes: &Grid<Node>, straight: Range<usize>) -> Result<Answer, ParseError> {
+    let map: Map = Map::new(nodes);
+
+    let end = Position::new(map.nodes.rows() - 1, map.nodes.cols() - 1);
+    let (_, heat_loss) = map
+        .find_path(Position::new(0, 0), end, straight)
+        .ok_or_else(|| ParseError::new("no path reaches the bottom-right block"))?;
+
+    Ok(heat_loss.into())
+}
+
+// The path of the crucible over the blocks, the more heat a block loses the redder it is
+pub fn frames(nodes: &Grid<Node>, part: u8) -> Vec<Frame> {
+    let straight = match part {
+        1 => Part1Config::default().min_straight..Part1Config::default().max_straight,
+        _ => Part2Config::default().min_straight..Part2Config::default().max_straight,
+    };
+
+    let map: Map = Map::new(nodes);
+    let end = Position::new(nodes.rows() - 1, nodes.cols() - 1);
+    // Without a path the blocks are drawn on their own
+    let (path, heat_loss) = map.find_path(Position::new(0, 0), end, straight).unwrap_or_default();
+    let steps: HashMap<Position, usize> = path.iter().enumerate().map(|(index, &position)| (position, index)).collect();
+
+    let frame = Frame::from_grid(nodes, |position, node| match steps.get(&position) {
+        Some(0) => Cell::new('#', Color::WHITE),
+        Some(&index) => {
+            let previous = path[index - 1];
+            let arrow = if previous.col < position.col {
+                '>'
+            } else if previous.col > position.col {
+                '<'
+            } else if previous.row < position.row {
+                'v'
+            } else {
+                '^'
+            };
+
+            Cell::new(arrow, Color::WHITE)
+        },
+        None => {
+            let glyph = char::from_digit(node.heat_loss as u32, 10).unwrap_or('?');
+            Cell::new(glyph, Color::DIM.mix(Color::RED, node.heat_loss as f32 / 9.0))
+        },
+    });
+
+    vec![frame.with_caption(format!("heat loss {}", heat_loss))]
+}
+
+// Config
+// -----------------------------------------------------------------------------
+
+// How many blocks the crucible must and can move in a straight line before turning
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part1Config {
+    pub min_straight: usize,
+    pub max_straight: usize,
+}
+
+impl Default for Part1Config {
+    fn default() -> Self {
+        Self { min_straight: 0, max_straight: 3 }
+    }
+}
+
+impl Parameters for Part1Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+
+        let (min_straight, max_straight) = straight(
+            params.take("min_straight", default.min_straight)?,
+            params.take("max_straight", default.max_straight)?,
+        )?;
+
+        Ok(Self { min_straight, max_straight })
+    }
+}
+
+// Same settings for the ultra crucible
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part2Config {
+    pub min_straight: usize,
+    pub max_straight: usize,
+}
+
+impl Default for Part2Config {
+    fn default() -> Self {
+        Self { min_straight: 4, max_straight: 10 }
+    }
+}
+
+impl Parameters for Part2Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+
+        let (min_straight, max_straight) = straight(
+            params.take("min_straight", default.min_straight)?,
+            params.take("max_straight", default.max_straight)?,
+        )?;
+
+        Ok(Self { min_straight, max_straight })
+    }
+}
+
+fn straight(min_straight: usize, max_straight: usize) -> Result<(usize, usize), ParseError> {
+    if min_straight > max_straight {
+        return Err(ParseError::new(format!(
+            "invalid values for parameters `min_straight` and `max_straight`: {} is more than {}",
+            min_straight, max_straight
+        )));
+    }
+
+    Ok((min_straight, max_straight))
+}
+
+// Node
+// -----------------------------------------------------------------------------
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node {
+    heat_loss: usize,
+}
+
+impl TryFrom<char> for Node {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let heat_loss = c.to_digit(10).ok_or_else(|| parse::unexpected(c))? as usize;
+
+        Ok(Self { heat_loss })
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.heat_loss)
+    }
+}
+
+// Crucible
+// -----------------------------------------------------------------------------
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Crucible {
+    position: Position,
+    direction_tracker: (Direction, usize),
+}
+
+// Map
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct Map<'a> {
+    nodes: &'a Grid<Node>,
+}
+
+impl<'a> Map<'a> {
+    fn new(nodes: &'a Grid<Node>) -> Self {
+        Self { nodes }
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        current_direction_tracker: &(Direction, usize),
+        direction_boundary: &Range<usize>
+    ) -> Vec<(Direction, Position)> {
+        let mut neighbours: Vec<(Direction, Position)> = vec![];
+
+        for (direction, neighbour) in self.nodes.neighbours(position) {
+            let breaking_conditions: bool = [
+                // Cannot go back in the opposite direction
+                current_direction_tracker.0.opposite() == direction,
+
+                // Cannot walk less than <boundary_start> or more than <boundary_end> steps on the same direction
+                current_direction_tracker.1 == direction_boundary.end && direction == current_direction_tracker.0,
+                current_direction_tracker.1 < direction_boundary.start && direction != current_direction_tracker.0,
+            ].iter().any(|c| *c);
+
+            if breaking_conditions {
+                continue;
+            }
+
+            neighbours.push((direction, neighbour));
+        }
+        neighbours
+    }
+
+    fn find_path(&self, start: Position, end: Position, straight: Range<usize>) -> Option<(Vec<Position>, usize)> {
+        let starts = [Direction::Right, Direction::Down]
+            .map(|direction| Crucible { position: start, direction_tracker: (direction, 0) });
+
+        let successors = |&crucible: &Crucible| {
+            self.neighbours(crucible.position, &crucible.direction_tracker, &straight)
+                .into_iter()
+                .map(move |(direction, neighbour)| {
+                    let direction_tracker = if crucible.direction_tracker.0 == direction {
+                        (direction, crucible.direction_tracker.1 + 1)
+                    } else {
+                        (direction, 1)
+                    };
+
+                    (Crucible { position: neighbour, direction_tracker }, self.nodes[neighbour].heat_loss)
+                })
+        };
+
+        let path: (Vec<Position>, usize) = search::dijkstra(starts, successors, |crucible| crucible.position == end && crucible.direction_tracker.1 >= straight.start)
+            .map(|path| (path.states.iter().map(|crucible| crucible.position).collect(), path.cost))?;
+        tracing::debug!(heat_loss = path.1, blocks = path.0.len(), path = ?path.0, "found the path");
+
+        Some(path)
+    }
+}
@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+use rand::Rng;
+use aoc_core::{generate, Generated, Position};
+
+// A dig plan around a random shape grown on a `size` × `size` grid, whose rows and columns are
+// stretched to up to 9 meters for the instructions and to as many as the 5 hexadecimal digits of
+// the colors allow for part 2. Both volumes are known: the cells of the shape are rectangles and
+// the trench adds half of its length plus one, by Pick's theorem.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let side = size.max(1);
+    let shape = generate::polyomino(rng, side, side * side / 2);
+    let corners = generate::boundary(&shape);
+
+    // The straight runs of the boundary, it starts at a corner of the shape
+    let mut runs: Vec<(char, Position, Position)> = vec![];
+    for (corner, next) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+        let direction = match (next.row.cmp(&corner.row), next.col.cmp(&corner.col)) {
+            (Ordering::Less, _) => 'U',
+            (Ordering::Greater, _) => 'D',
+            (_, Ordering::Less) => 'L',
+            _ => 'R',
+        };
+
+        match runs.last_mut() {
+            Some((run, _, to)) if *run == direction => *to = *next,
+            _ => runs.push((direction, *corner, *next)),
+        }
+    }
+
+    let longest = (0xfffff / side as u64).max(1);
+    let (rows, cols) = (stretch(rng, side, 9), stretch(rng, side, 9));
+    let (color_rows, color_cols) = (stretch(rng, side, longest), stretch(rng, side, longest));
+
+    let mut input = String::new();
+    for (direction, from, to) in &runs {
+        let meters = |rows: &[u64], cols: &[u64]| rows[from.row].abs_diff(rows[to.row]) + cols[from.col].abs_diff(cols[to.col]);
+        let code = "RDLU".find(*direction).unwrap();
+
+        input.push_str(&format!("{} {} (#{:05x}{})\n", direction, meters(&rows, &cols), meters(&color_rows, &color_cols), code));
+    }
+
+    let volume = |rows: &[u64], cols: &[u64]| {
+        let area: u64 = shape
+            .iter()
+            .filter(|(_, &filled)| filled)
+            .map(|(cell, _)| (rows[cell.row + 1] - rows[cell.row]) * (cols[cell.col + 1] - cols[cell.col]))
+            .sum();
+        let trench: u64 = corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .map(|(corner, next)| rows[corner.row].abs_diff(rows[next.row]) + cols[corner.col].abs_diff(cols[next.col]))
+            .sum();
+
+        area + trench / 2 + 1
+    };
+
+    Generated::new(input)
+        .with_answer(1, volume(&rows, &cols))
+        .with_answer(2, volume(&color_rows, &color_cols))
+}
+
+// Where the `side + 1` lines between the rows (or columns) end up, each row being 1 to `longest`
+// meters wide
+fn stretch<R: Rng>(rng: &mut R, side: usize, longest: u64) -> Vec<u64> {
+    let mut lines: Vec<u64> = vec![0];
+    for _ in 0..side {
+        lines.push(lines[lines.len() - 1] + rng.gen_range(1..=longest));
+    }
+
+    lines
+}
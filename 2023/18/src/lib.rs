@@ -0,0 +1,271 @@
+use std::{fmt, str::FromStr};
+use aoc_core::{math, parse, Answer, Frame, Grid, ParseError, Position};
+use aoc_core::visual::{Cell, Color};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<PlanEntry>, ParseError> {
+    let instructions: Vec<PlanEntry> = input
+        .split('\n')
+        .filter(|row| !row.is_empty())
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, "expected at least one dig instruction"));
+    }
+
+    Ok(instructions)
+}
+
+pub fn render(plan: &[PlanEntry]) -> String {
+    plan.iter().map(|entry| format!("{}\n", entry)).collect()
+}
+
+pub fn part1(plan: &[PlanEntry]) -> Result<Answer, ParseError> {
+    let lagoon: Lagoon = plan.iter().map(|entry| &entry.instruction).collect();
+
+    lagoon.volume().map(Answer::from).ok_or_else(too_large)
+}
+
+pub fn part2(plan: &[PlanEntry]) -> Result<Answer, ParseError> {
+    let lagoon: Lagoon = plan.iter().map(|entry| &entry.color_instruction).collect();
+
+    lagoon.volume().map(Answer::from).ok_or_else(too_large)
+}
+
+fn too_large() -> ParseError {
+    ParseError::new("the lagoon is too large to measure")
+}
+
+// The trenches dug by the plan of the part
+pub fn frames(plan: &[PlanEntry], part: u8) -> Vec<Frame> {
+    let lagoon: Lagoon = match part {
+        1 => plan.iter().map(|entry| &entry.instruction).collect(),
+        _ => plan.iter().map(|entry| &entry.color_instruction).collect(),
+    };
+
+    let caption = match lagoon.volume() {
+        Some(volume) => format!("{} cubic meters", volume),
+        None => "too large to measure".to_string(),
+    };
+
+    vec![lagoon.frame().with_caption(caption)]
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' => Ok(Direction::Up),
+            'D' => Ok(Direction::Down),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct TrenchInstruction {
+    direction: Direction,
+    meters: i64,
+}
+
+// A line of the dig plan: the instruction as written and the one actually encoded in its color
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlanEntry {
+    instruction: TrenchInstruction,
+    color_instruction: TrenchInstruction,
+}
+
+impl FromStr for PlanEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let row: Vec<&str> = s.split(' ').collect();
+        let [direction, meters, color] = row[..] else {
+            return Err(ParseError::at(s, format!("expected `<direction> <meters> (<color>)`, found `{}`", s)));
+        };
+        let color = color
+            .strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .filter(|color| color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| ParseError::at(color, format!("expected a color like `(#70c710)`, found `{}`", color)))?;
+        let mut chars = direction.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return Err(ParseError::at(direction, format!("expected a single direction, found `{}`", direction)));
+        };
+
+        let instruction = TrenchInstruction {
+            direction: Direction::try_from(c).map_err(|e| e.with_location(direction))?,
+            meters: parse::number(meters)?,
+        };
+
+        Ok(PlanEntry {
+            instruction,
+            color_instruction: TrenchInstruction::from_color(color)?,
+        })
+    }
+}
+
+// The color is made up from the instruction it encodes
+impl fmt::Display for PlanEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = |direction: &Direction| match direction {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        let digit = match self.color_instruction.direction {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        write!(
+            f,
+            "{} {} (#{:05x}{})",
+            letter(&self.instruction.direction),
+            self.instruction.meters,
+            self.color_instruction.meters,
+            digit
+        )
+    }
+}
+
+impl TrenchInstruction {
+    // The first five hexadecimal digits are the distance, the last one the direction
+    fn from_color(color: &str) -> Result<Self, ParseError> {
+        let (distance, direction) = color.split_at(5);
+
+        let meters: i64 = i64::from_str_radix(distance, 16)
+            .map_err(|_| ParseError::at(distance, format!("expected a hexadecimal distance, found `{}`", distance)))?;
+
+        let direction: Direction = match direction {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(ParseError::at(direction, format!("expected a direction between 0 and 3, found `{}`", direction)))
+        };
+
+        Ok(TrenchInstruction { direction, meters })
+    }
+
+    fn end(&self, start: (i64, i64)) -> (i64, i64) {
+        match self.direction {
+            Direction::Up => (start.0 - self.meters, start.1),
+            Direction::Down => (start.0 + self.meters, start.1),
+            Direction::Left => (start.0, start.1 - self.meters),
+            Direction::Right => (start.0, start.1 + self.meters),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Trench {
+    start: (i64, i64),
+    end: (i64, i64)
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Lagoon {
+    trenches: Vec<Trench>
+}
+
+impl<'a> FromIterator<&'a TrenchInstruction> for Lagoon {
+    fn from_iter<I: IntoIterator<Item = &'a TrenchInstruction>>(instructions: I) -> Self {
+        let mut trenches: Vec<Trench> = vec![];
+        let mut current_coordinates: (i64, i64) = (0, 0);
+
+        for instruction in instructions {
+            let start = current_coordinates;
+            let end = instruction.end(start);
+
+            trenches.push(Trench { start, end });
+
+            current_coordinates = end;
+        }
+
+        let normalized_parameter_for_x = trenches.iter().map(|trench| trench.start.0).min().unwrap();
+        let normalized_parameter_for_y = trenches.iter().map(|trench| trench.start.1).min().unwrap();
+
+        for trench in &mut trenches {
+            trench.start.0 += -normalized_parameter_for_x;
+            trench.start.1 += -normalized_parameter_for_y;
+            trench.end.0 += -normalized_parameter_for_x;
+            trench.end.1 += -normalized_parameter_for_y;
+        }
+
+        Self { trenches }
+    }
+}
+
+impl Lagoon {
+    // Lagoons too large to draw meter by meter only keep the rows and columns of their corners,
+    // with a single one between two of them
+    fn frame(&self) -> Frame {
+        const MAX_SIDE: i64 = 512;
+
+        let axis = |coordinates: Vec<i64>| {
+            let mut corners = coordinates;
+            corners.sort();
+            corners.dedup();
+
+            move |coordinate: i64| -> usize {
+                if corners.last().is_some_and(|&last| last < MAX_SIDE) {
+                    coordinate as usize
+                } else {
+                    2 * corners.binary_search(&coordinate).expect("trenches go from corner to corner")
+                }
+            }
+        };
+        let row = axis(self.trenches.iter().map(|trench| trench.start.0).collect());
+        let col = axis(self.trenches.iter().map(|trench| trench.start.1).collect());
+
+        let rows = self.trenches.iter().map(|trench| row(trench.start.0)).max().unwrap_or(0) + 1;
+        let cols = self.trenches.iter().map(|trench| col(trench.start.1)).max().unwrap_or(0) + 1;
+        let mut cells: Grid<Cell> = Grid::new(rows, cols, Cell::new('.', Color::DIM));
+
+        for trench in &self.trenches {
+            let (start, end) = ((row(trench.start.0), col(trench.start.1)), (row(trench.end.0), col(trench.end.1)));
+            let (start, end) = (start.min(end), start.max(end));
+
+            for r in start.0..=end.0 {
+                for c in start.1..=end.1 {
+                    cells[Position::new(r, c)] = Cell::new('#', Color::YELLOW);
+                }
+            }
+        }
+
+        Frame::new(cells)
+    }
+
+    fn corners(&self) -> Vec<(i64, i64)> {
+        self.trenches.iter().map(|trench| trench.start).collect()
+    }
+
+    // The trenches themselves and the points inside them, by Pick's theorem
+    fn volume(&self) -> Option<u64> {
+        let corners = self.corners();
+        let trenches = math::boundary_points(&corners)?;
+
+        trenches.checked_add(math::interior_points(math::shoelace(&corners)?, trenches)?)
+    }
+}
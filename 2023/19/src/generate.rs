@@ -0,0 +1,40 @@
+use std::collections::VecDeque;
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::{generate, Generated};
+
+// Up to `size` workflows sending the parts down a tree from `in`, so that none of them loops, and
+// `size` parts rated from 1 to 4000
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let mut names = generate::names(rng, size.saturating_sub(1), 3, |_| true).into_iter();
+    let mut pending: VecDeque<String> = VecDeque::from(["in".to_string()]);
+    let mut input = String::new();
+
+    while let Some(name) = pending.pop_front() {
+        let mut target = |rng: &mut R| match names.next() {
+            Some(child) if rng.gen_bool(0.6) => {
+                pending.push_back(child.clone());
+                child
+            },
+            _ => if rng.gen_bool(0.5) { "A".to_string() } else { "R".to_string() },
+        };
+
+        let mut rules: Vec<String> = (0..rng.gen_range(1..=3))
+            .map(|_| {
+                let category = *['x', 'm', 'a', 's'].choose(rng).unwrap();
+                let operator = *['<', '>'].choose(rng).unwrap();
+                format!("{}{}{}:{}", category, operator, rng.gen_range(1..=4000), target(rng))
+            })
+            .collect();
+        rules.push(target(rng));
+
+        input.push_str(&format!("{}{{{}}}\n", name, rules.join(",")));
+    }
+
+    input.push('\n');
+    for _ in 0..size {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        input.push_str(&format!("{{x={},m={},a={},s={}}}\n", x, m, a, s));
+    }
+
+    Generated::new(input)
+}
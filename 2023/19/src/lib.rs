@@ -0,0 +1,497 @@
+use std::{collections::{HashMap, HashSet}, fmt, ops::Range, str::FromStr, sync::LazyLock};
+use regex::Regex;
+use aoc_core::{interval::Cuboid, parse, Answer, ParseError};
+
+pub mod generate;
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    // Only part 1 rates actual parts, part 2 considers every possible rating
+    part_ratings: Option<Vec<PartRating>>,
+}
+
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    let (workflows, part_ratings) = match input.split_once("\n\n") {
+        Some((workflows, part_ratings)) => (workflows, Some(part_ratings)),
+        None => (input, None),
+    };
+
+    let workflows: HashMap<String, Workflow> = parse_workflows(workflows)?;
+
+    let part_ratings: Option<Vec<PartRating>> = part_ratings
+        .map(|part_ratings| part_ratings
+            .split('\n')
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>())
+        .transpose()?;
+
+    Ok(System { workflows, part_ratings })
+}
+
+pub fn render(system: &System) -> String {
+    let mut workflows: Vec<&Workflow> = system.workflows.values().collect();
+    workflows.sort_by_key(|workflow| &workflow.name);
+
+    let mut input: String = workflows.iter().map(|workflow| format!("{}\n", workflow)).collect();
+    if let Some(part_ratings) = &system.part_ratings {
+        input.push('\n');
+        input.extend(part_ratings.iter().map(|part_rating| format!("{}\n", part_rating)));
+    }
+
+    input
+}
+
+pub fn part1(system: &System) -> Result<Answer, ParseError> {
+    let Some(part_ratings) = &system.part_ratings else {
+        return Err(ParseError::new("expected the part ratings after the workflows"));
+    };
+    let mut sum: usize = 0;
+
+    for part_rating in part_ratings {
+        let mut response: WorkflowResponse = WorkflowResponse::Routed("in".to_string());
+
+        while let WorkflowResponse::Routed(workflow) = response {
+            response = system.workflows[&workflow].run(part_rating);
+        }
+
+        if response == WorkflowResponse::Accepted {
+            sum += part_rating.x + part_rating.m + part_rating.a + part_rating.s;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+pub fn part2(system: &System) -> Answer {
+    let combinations: u64 = valid_ranges(system)
+        .iter()
+        .map(|valid_range| valid_range.volume().expect("4000 ratings on 4 categories fit in a u64"))
+        .sum();
+
+    combinations.into()
+}
+
+// The ranges of ratings accepted by the workflows
+#[cfg(feature = "serde")]
+pub fn solved(system: &System) -> impl serde::Serialize {
+    valid_ranges(system)
+}
+
+fn valid_ranges(system: &System) -> Vec<PartRatingRange> {
+    let initial_part_rating_range: PartRatingRange = Cuboid::new([1..4001, 1..4001, 1..4001, 1..4001]);
+
+    let mut state = vec![WorkflowRangeResponse::Routed((initial_part_rating_range, "in".to_string()))];
+    let mut valid_ranges: Vec<PartRatingRange> = vec![];
+
+    while let Some(head) = state.pop() {
+        match head {
+            WorkflowRangeResponse::Routed((part_rating_range, workflow)) => {
+                state.append(&mut system.workflows[&workflow].run_range(&part_rating_range));
+            },
+            WorkflowRangeResponse::Refused(_) => {
+                continue;
+            },
+            WorkflowRangeResponse::Accepted(part_rating_range) => {
+                valid_ranges.push(part_rating_range);
+            },
+        }
+    }
+
+    valid_ranges
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct PartRating {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl PartRating {
+    fn get_rating(&self, category: &Category) -> usize {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s
+        }
+    }
+}
+
+impl FromStr for PartRating {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ratings = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(s, format!("expected `{{x=<x>,m=<m>,a=<a>,s=<s>}}`, found `{}`", s)))?;
+
+        let mut map: HashMap<&str, usize> = HashMap::new();
+        for rate in ratings.split(',') {
+            let (category, value) = parse::split_once(rate, "=")?;
+            map.insert(category, parse::number(value)?);
+        }
+
+        let rating = |category: &str| map
+            .get(category)
+            .copied()
+            .ok_or_else(|| ParseError::at(s, format!("expected a rating for `{}`", category)));
+
+        Ok(Self {
+            x: rating("x")?,
+            m: rating("m")?,
+            a: rating("a")?,
+            s: rating("s")?,
+        })
+    }
+}
+
+impl fmt::Display for PartRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
+// The ratings of the categories in order, as the sides of a box
+type PartRatingRange = Cuboid<4>;
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Category {
+    X,
+    M,
+    A,
+    S
+}
+
+impl Category {
+    fn axis(&self) -> usize {
+        match self {
+            Category::X => 0,
+            Category::M => 1,
+            Category::A => 2,
+            Category::S => 3,
+        }
+    }
+}
+
+impl TryFrom<char> for Category {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'x' => Ok(Category::X),
+            'm' => Ok(Category::M),
+            'a' => Ok(Category::A),
+            's' => Ok(Category::S),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Operator {
+    LesserThan,
+    GreaterThan,
+    EqualTo
+}
+
+impl TryFrom<char> for Operator {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '<' => Ok(Operator::LesserThan),
+            '>' => Ok(Operator::GreaterThan),
+            '=' => Ok(Operator::EqualTo),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Rule {
+    category: Option<Category>,
+    operator: Option<Operator>,
+    value: Option<usize>,
+    if_true: String
+}
+
+impl Rule {
+    fn apply(&self, part_rating: &PartRating) -> Option<String> {
+        match self.operator {
+            Some(Operator::LesserThan) => {
+                if part_rating.get_rating(self.category.as_ref().unwrap()) < self.value.unwrap() {
+                    Some(self.if_true.clone())
+                } else {
+                    None
+                }
+            },
+            Some(Operator::GreaterThan) => {
+                if part_rating.get_rating(self.category.as_ref().unwrap()) > self.value.unwrap() {
+                    Some(self.if_true.clone())
+                } else {
+                    None
+                }
+            },
+            Some(Operator::EqualTo) => {
+                if part_rating.get_rating(self.category.as_ref().unwrap()) == self.value.unwrap() {
+                    Some(self.if_true.clone())
+                } else {
+                    None
+                }
+            },
+            None => Some(self.if_true.clone())
+        }
+    }
+
+    // The ratings the condition holds for, on the axis of its category. The last rule of a workflow
+    // has none and takes every rating left.
+    fn condition(&self) -> Option<(usize, Range<i64>)> {
+        let (category, operator, value) = (self.category.as_ref()?, self.operator.as_ref()?, self.value?);
+        let value = i64::try_from(value).unwrap_or(i64::MAX);
+
+        let ratings = match operator {
+            Operator::LesserThan => i64::MIN..value,
+            Operator::GreaterThan => value.saturating_add(1)..i64::MAX,
+            Operator::EqualTo => value..value.saturating_add(1),
+        };
+
+        Some((category.axis(), ratings))
+    }
+}
+
+static RULE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?<category>[xmas])(?<operator>[=<>])(?<value>\d+):(?<if_true>\w+)$").unwrap());
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            let captures = RULE
+                .captures(s)
+                .ok_or_else(|| ParseError::at(s, format!("expected `<category><operator><value>:<workflow>`, found `{}`", s)))?;
+
+            Ok(Self {
+                category: Some(Category::try_from(s.chars().next().unwrap()).map_err(|e| e.with_location(s))?),
+                operator: Some(Operator::try_from(captures["operator"].chars().next().unwrap())?),
+                value: Some(parse::number(captures.name("value").unwrap().as_str())?),
+                if_true: captures["if_true"].to_string(),
+            })
+        } else if !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            Ok(Self {
+                category: None,
+                operator: None,
+                value: None,
+                if_true: s.to_string(),
+            })
+        } else {
+            Err(ParseError::at(s, format!("expected a workflow name, found `{}`", s)))
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let (Some(category), Some(operator), Some(value)) = (&self.category, &self.operator, self.value) {
+            let category = match category {
+                Category::X => 'x',
+                Category::M => 'm',
+                Category::A => 'a',
+                Category::S => 's',
+            };
+            let operator = match operator {
+                Operator::LesserThan => '<',
+                Operator::GreaterThan => '>',
+                Operator::EqualTo => '=',
+            };
+            write!(f, "{}{}{}:", category, operator, value)?;
+        }
+
+        write!(f, "{}", self.if_true)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum WorkflowResponse {
+    Accepted,
+    Refused,
+    Routed(String)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum WorkflowRangeResponse {
+    Accepted(PartRatingRange),
+    Refused(PartRatingRange),
+    Routed((PartRatingRange, String))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Workflow {
+    name: String,
+    rules: HashMap<usize, Rule>
+}
+
+impl Workflow {
+    fn run(&self, part_rating: &PartRating) -> WorkflowResponse {
+        for idx in 0..self.rules.len() {
+            match self.rules[&idx].apply(part_rating) {
+                None => { continue; },
+                Some(answer) => {
+                    let response = match answer.as_str() {
+                        "R" => WorkflowResponse::Refused,
+                        "A" => WorkflowResponse::Accepted,
+                        workflow => WorkflowResponse::Routed(workflow.to_string())
+                    };
+                    return response;
+                }
+            }
+        }
+        unreachable!();
+    }
+
+    // Each rule takes the part of the ranges its condition holds for, and leaves the rest to the
+    // next ones
+    fn run_range(&self, part_rating_range: &PartRatingRange) -> Vec<WorkflowRangeResponse> {
+        let mut results: Vec<WorkflowRangeResponse> = vec![];
+        let mut part_rating_ranges_left: Vec<PartRatingRange> = vec![part_rating_range.clone()];
+
+        for idx in 0..self.rules.len() {
+            let rule = &self.rules[&idx];
+
+            let mut applied: Vec<PartRatingRange> = vec![];
+            let mut left: Vec<PartRatingRange> = vec![];
+
+            for range in part_rating_ranges_left {
+                match rule.condition() {
+                    None => applied.push(range),
+                    Some((axis, ratings)) => match range.restrict(axis, ratings) {
+                        Some(matching) => {
+                            left.extend(range.difference(&matching));
+                            applied.push(matching);
+                        },
+                        None => left.push(range),
+                    },
+                }
+            }
+
+            for new_part_rating_range in applied {
+                results.push(match rule.if_true.as_str() {
+                    "R" => WorkflowRangeResponse::Refused(new_part_rating_range),
+                    "A" => WorkflowRangeResponse::Accepted(new_part_rating_range),
+                    workflow => WorkflowRangeResponse::Routed((new_part_rating_range, workflow.to_string())),
+                });
+            }
+
+            part_rating_ranges_left = left;
+        }
+        results
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, rules_input) = parse::split_once(s, "{")?;
+        let rules_input = rules_input
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::at(rules_input, "expected the rules to end with `}`"))?;
+
+        let mut rules: HashMap<usize, Rule> = HashMap::new();
+
+        for (idx, rule) in rules_input.split(',').enumerate() {
+            rules.insert(idx, rule.parse()?);
+        }
+
+        if rules[&(rules.len() - 1)].operator.is_some() {
+            return Err(ParseError::at(s, format!("expected the last rule of `{}` to have no condition", name)));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            rules
+        })
+    }
+}
+
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<String> = (0..self.rules.len()).map(|idx| self.rules[&idx].to_string()).collect();
+        write!(f, "{}{{{}}}", self.name, rules.join(","))
+    }
+}
+
+fn parse_workflows(s: &str) -> Result<HashMap<String, Workflow>, ParseError> {
+    let mut workflows: HashMap<String, Workflow> = HashMap::new();
+
+    for line in s.split('\n').filter(|s| !s.is_empty()) {
+        let workflow: Workflow = line.parse()?;
+        workflows.insert(workflow.name.clone(), workflow);
+    }
+
+    for line in s.split('\n').filter(|s| !s.is_empty()) {
+        let workflow = &workflows[line.split('{').next().unwrap()];
+        for rule in workflow.rules.values() {
+            if !["A", "R"].contains(&rule.if_true.as_str()) && !workflows.contains_key(&rule.if_true) {
+                return Err(ParseError::at(line, format!("unknown workflow `{}`", rule.if_true)));
+            }
+        }
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::at(s, "expected an `in` workflow"));
+    }
+
+    // A part routed back to a workflow it went through would go round forever
+    let mut checked: HashSet<&str> = HashSet::new();
+    for line in s.split('\n').filter(|s| !s.is_empty()) {
+        let name = line.split('{').next().unwrap();
+        if let Some(routes) = find_loop(&workflows, name, &mut vec![], &mut checked) {
+            let routes: Vec<String> = routes.iter().map(|name| format!("`{}`", name)).collect();
+            return Err(ParseError::at(line, format!("workflows route in a loop: {}", routes.join(" → "))));
+        }
+    }
+
+    Ok(workflows)
+}
+
+// The workflows of a loop reachable from `name`, the first one ending it too. `path` holds the
+// workflows on the way to `name`, `checked` those known not to lead to a loop.
+fn find_loop<'a>(
+    workflows: &'a HashMap<String, Workflow>,
+    name: &'a str,
+    path: &mut Vec<&'a str>,
+    checked: &mut HashSet<&'a str>,
+) -> Option<Vec<&'a str>> {
+    if let Some(start) = path.iter().position(|on_path| *on_path == name) {
+        return Some(path[start..].iter().copied().chain([name]).collect());
+    }
+    // `A` and `R` end the routes
+    let workflow = workflows.get(name)?;
+    if checked.contains(name) {
+        return None;
+    }
+
+    path.push(name);
+    for idx in 0..workflow.rules.len() {
+        if let Some(routes) = find_loop(workflows, &workflow.rules[&idx].if_true, path, checked) {
+            return Some(routes);
+        }
+    }
+    path.pop();
+    checked.insert(name);
+
+    None
+}
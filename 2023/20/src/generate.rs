@@ -0,0 +1,55 @@
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::{generate, math, Generated};
+
+// Flip-flops of a counter
+const BITS: usize = 12;
+
+// A network laid out like in the puzzle: the broadcaster starts `size` counters (5 at most) of 12
+// flip-flops, each with a conjunction that wires them to reset at an odd number of 12 bits and then
+// sends a high pulse to `rx`'s conjunction through an inverter. The presses of part 2, the least
+// common multiple of these numbers, are known.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let counters = size.clamp(1, 5);
+    let mut names = generate::names(rng, counters * (BITS + 2) + 1, 2, |name| name != "rx").into_iter();
+    let mut names = move || names.next().unwrap();
+
+    let mut periods: Vec<u64> = ((1 << (BITS - 1)) + 1..1 << BITS).step_by(2).collect();
+    periods.shuffle(rng);
+    periods.truncate(counters);
+
+    let output = names();
+    let mut modules: Vec<String> = vec![format!("&{} -> rx", output)];
+    let mut starts: Vec<String> = vec![];
+
+    for &period in &periods {
+        let flip_flops: Vec<String> = (0..BITS).map(|_| names()).collect();
+        let (hub, inverter) = (names(), names());
+
+        // Once all the flip-flops of the 1 bits are on, the hub pulses the first flip-flop and those
+        // of the 0 bits, which carries the counter over back to 0
+        let mut resets: Vec<&str> = vec![&inverter, &flip_flops[0]];
+
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations: Vec<&str> = flip_flops.get(bit + 1).map(String::as_str).into_iter().collect();
+            if period >> bit & 1 == 1 {
+                destinations.push(&hub);
+            } else {
+                resets.push(flip_flop);
+            }
+            destinations.shuffle(rng);
+
+            modules.push(format!("%{} -> {}", flip_flop, destinations.join(", ")));
+        }
+
+        modules.push(format!("&{} -> {}", hub, resets.join(", ")));
+        modules.push(format!("&{} -> {}", inverter, output));
+        starts.push(flip_flops[0].clone());
+    }
+
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    modules.shuffle(rng);
+
+    let presses = math::lcm_all(periods.iter().copied()).expect("5 periods of 12 bits fit in a u64");
+
+    Generated::new(modules.join("\n") + "\n").with_answer(2, presses)
+}
@@ -0,0 +1,354 @@
+use std::{collections::{HashMap, VecDeque}, cell::RefCell, fmt, str::FromStr, sync::LazyLock};
+use regex::Regex;
+use aoc_core::{cycle, math, parse, Answer, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let mut modules: HashMap<String, Module> = input
+        .split('\n')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<Module>().map(|module| (module.name.clone(), module)))
+        .collect::<Result<_, _>>()?;
+
+    let conjunction_modules: Vec<String> = modules
+        .iter()
+        .filter(|(_, module)| {
+            matches!(*module.module_type.borrow(), ModuleType::Conjunction(_))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    for module in conjunction_modules {
+        let inputs: Vec<String> = modules
+            .iter()
+            .filter(|(_, m)| {
+                m.destination_modules.contains(&module)
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        modules.entry(module).and_modify(|e| {
+            if let ModuleType::Conjunction(ref mut map) = *e.module_type.borrow_mut() {
+
+                for input in inputs.iter() {
+                    map.insert(input.to_string(), Pulse::Low);
+                }
+
+            }
+        });
+    }
+
+    Ok(modules)
+}
+
+// The inputs of the conjunctions are not written, they are found again from the destinations
+pub fn render(modules: &HashMap<String, Module>) -> String {
+    let mut modules: Vec<&Module> = modules.values().collect();
+    modules.sort_by_key(|module| &module.name);
+
+    modules.iter().map(|module| format!("{}\n", module)).collect()
+}
+
+pub fn part1(modules: &HashMap<String, Module>) -> Answer {
+    // Modules keep their state while pulses go through them
+    let modules: HashMap<String, Module> = modules.clone();
+
+    let mut low_pulses: u64 = 0;
+    let mut high_pulses: u64 = 0;
+
+    for press in 1..=1000 {
+        push_button(&modules, |_, _, pulse| match pulse {
+            Pulse::Low => low_pulses += 1,
+            Pulse::High => high_pulses += 1,
+        });
+        tracing::debug!(press, low_pulses, high_pulses, "button pressed");
+    }
+
+    // Past a u64, the product is kept whole in a u128
+    high_pulses
+        .checked_mul(low_pulses)
+        .map_or_else(|| (u128::from(high_pulses) * u128::from(low_pulses)).into(), Answer::from)
+}
+
+// `rx` gets a low pulse once every module feeding its conjunction sends it a high pulse during the
+// same press. The modules upstream of a feeder go round in circles on their own, the feeder sending
+// its high pulse at the same point of each: the press is the one where all of these points line up.
+pub fn part2(modules: &HashMap<String, Module>) -> Result<Answer, ParseError> {
+    let Some(conjunction) = modules
+        .values()
+        .find(|module| module.destination_modules.contains(&"rx".to_string()))
+        .map(|module| module.name.as_str())
+    else {
+        return Err(ParseError::new("expected a module sending pulses to `rx`"));
+    };
+
+    let mut feeders: Vec<&str> = modules
+        .values()
+        .filter(|module| module.destination_modules.iter().any(|destination| destination == conjunction))
+        .map(|module| module.name.as_str())
+        .collect();
+    feeders.sort_unstable();
+
+    let mut congruences: Vec<(i64, u64)> = vec![];
+    let mut first_press: u64 = 1;
+
+    for feeder in feeders {
+        let upstream: Vec<&str> = upstream(modules, feeder);
+        let mut press: usize = 0;
+        let mut high_pulses: Vec<usize> = vec![];
+
+        // Modules keep their state while pulses go through them. The steps go by in order, so the
+        // states are the ones of the upstream modules after each press.
+        let network: HashMap<String, Module> = modules.clone();
+        let found = cycle::hash_map(
+            state_of(&network, &upstream),
+            |_| {
+                press += 1;
+                push_button(&network, |source, destination, pulse| {
+                    if source == feeder && destination == conjunction && *pulse == Pulse::High {
+                        high_pulses.push(press);
+                    }
+                });
+                state_of(&network, &upstream)
+            },
+            Vec::clone,
+        );
+
+        high_pulses.retain(|&press| press > found.prefix);
+        high_pulses.dedup();
+        tracing::debug!(module = %feeder, start = found.prefix, length = found.period, presses = ?high_pulses, "feeder cycle");
+
+        let [press] = high_pulses[..] else {
+            return Err(ParseError::new(format!(
+                "expected `{}` to send one high pulse to `{}` every {} presses, found {}",
+                feeder, conjunction, found.period, high_pulses.len()
+            )));
+        };
+
+        congruences.push((press as i64, found.period as u64));
+        first_press = first_press.max(found.prefix as u64 + 1);
+    }
+
+    let too_many = || ParseError::new("`rx` only gets a low pulse after more presses than fit in a u64");
+    let (press, period) = math::crt(congruences).ok_or_else(too_many)?;
+
+    // The presses before the cycles start do not come back
+    let press = if press >= first_press {
+        press
+    } else {
+        let skipped = (first_press - press).div_ceil(period);
+        skipped.checked_mul(period).and_then(|skipped| skipped.checked_add(press)).ok_or_else(too_many)?
+    };
+
+    Ok(press.into())
+}
+
+// Pushes the button once, with `watch` told of every pulse on the way
+fn push_button(modules: &HashMap<String, Module>, mut watch: impl FnMut(&str, &str, &Pulse)) {
+    let mut pulses: VecDeque<(String, String, Pulse)> = VecDeque::from([(
+        "button".to_string(),
+        "broadcaster".to_string(),
+        Pulse::Low
+    )]);
+
+    while let Some((source, destination, pulse)) = pulses.pop_front() {
+        watch(&source, &destination, &pulse);
+        tracing::trace!(%source, ?pulse, %destination, "pulse");
+
+        if let Some(module) = modules.get(&destination) {
+            if let Some((new_destination_modules, new_pulse)) = module.handle(source, pulse) {
+                for new_destination in new_destination_modules {
+                    pulses.push_back((destination.clone(), new_destination, new_pulse.clone()));
+                }
+            }
+        }
+    }
+}
+
+// The module and the ones sending pulses to it on the way from the button, sorted
+fn upstream<'a>(modules: &'a HashMap<String, Module>, name: &'a str) -> Vec<&'a str> {
+    let mut found: Vec<&str> = vec![name];
+    let mut index = 0;
+
+    while let Some(&current) = found.get(index) {
+        for module in modules.values() {
+            if module.destination_modules.iter().any(|destination| destination == current) && !found.contains(&module.name.as_str()) {
+                found.push(&module.name);
+            }
+        }
+        index += 1;
+    }
+
+    found.sort_unstable();
+    found
+}
+
+// Whether each flip-flop is on and each conjunction remembers a high pulse, for the given modules
+fn state_of(modules: &HashMap<String, Module>, names: &[&str]) -> Vec<bool> {
+    let mut state: Vec<bool> = vec![];
+
+    for name in names {
+        match &*modules[*name].module_type.borrow() {
+            ModuleType::FlipFlop(status) => state.push(*status == ModuleStatus::On),
+            ModuleType::Conjunction(memory) => {
+                let mut inputs: Vec<(&String, &Pulse)> = memory.iter().collect();
+                inputs.sort_unstable_by_key(|(input, _)| *input);
+                state.extend(inputs.into_iter().map(|(_, pulse)| *pulse == Pulse::High));
+            },
+            ModuleType::Broadcast | ModuleType::Button | ModuleType::Untyped => {},
+        }
+    }
+
+    state
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Pulse {
+    Low,
+    High
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ModuleStatus {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ModuleType {
+    FlipFlop(ModuleStatus),
+    Conjunction(HashMap<String, Pulse>),
+    Broadcast,
+    #[allow(dead_code)]
+    Button,
+    Untyped,
+}
+
+
+
+trait PulseHandler {
+    fn handle(&self, source: String, pulse: Pulse) -> Option<(Vec<String>, Pulse)>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Module {
+    name: String,
+    destination_modules: Vec<String>,
+    module_type: RefCell<ModuleType>
+}
+
+impl PulseHandler for Module {
+    fn handle(&self, source: String, pulse: Pulse) -> Option<(Vec<String>, Pulse)>{
+        match *self.module_type.borrow_mut() {
+            ModuleType::Button => {
+                Some((vec!["broadcaster".to_string()], Pulse::Low))
+            },
+            ModuleType::Broadcast => {
+                Some((self.destination_modules.clone(), pulse))
+            },
+            ModuleType::FlipFlop(ref mut status) => {
+                match pulse {
+                    Pulse::High => None,
+                    Pulse::Low => {
+                        match status {
+                            ModuleStatus::Off => {
+                                *status = ModuleStatus::On;
+                                Some((self.destination_modules.clone(), Pulse::High))
+                            },
+                            ModuleStatus::On => {
+                                *status = ModuleStatus::Off;
+                                Some((self.destination_modules.clone(), Pulse::Low))
+                            },
+                        }
+                    }
+                }
+            },
+            ModuleType::Conjunction(ref mut map) => {
+                map.entry(source).and_modify(|e| *e = pulse);
+                if map.values().all(|p| *p == Pulse::High) {
+                    Some((self.destination_modules.clone(), Pulse::Low))
+                } else {
+                    Some((self.destination_modules.clone(), Pulse::High))
+                }
+            },
+            ModuleType::Untyped => None,
+        }
+    }
+}
+
+static MODULE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?<type>broadcaster|%|&)(?<name>\w*)$").unwrap());
+
+impl FromStr for Module {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (module, destinations) = parse::split_once(s, " -> ")?;
+
+        let captures = MODULE
+            .captures(module)
+            .ok_or_else(|| ParseError::at(module, format!("expected `broadcaster`, `%<name>` or `&<name>`, found `{}`", module)))?;
+
+        let module_type: &str = &captures["type"];
+        let module_name: &str = &captures["name"];
+        if (module_type == "broadcaster") != module_name.is_empty() {
+            return Err(ParseError::at(module, format!("expected `broadcaster`, `%<name>` or `&<name>`, found `{}`", module)));
+        }
+
+        let destination_modules: Vec<&str> = destinations
+            .split(',')
+            .map(|s| s.trim())
+            .collect();
+        if let Some(destination) = destination_modules.iter().find(|s| s.is_empty()) {
+            return Err(ParseError::at(destination, "expected a destination module"));
+        }
+
+        let module = match module_type {
+            "broadcaster" => Self {
+                name: "broadcaster".to_string(),
+                destination_modules: destination_modules.iter().map(|s| s.to_string()).collect(),
+                module_type: RefCell::new(ModuleType::Broadcast)
+            },
+            "%" => Self {
+                name: module_name.to_string(),
+                destination_modules: destination_modules.iter().map(|s| s.to_string()).collect(),
+                module_type: RefCell::new(ModuleType::FlipFlop(ModuleStatus::Off))
+            },
+            "&" => {
+                let destination_modules: Vec<String> = destination_modules
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect();
+                let conjunction_map: HashMap<String, Pulse> = HashMap::new();
+
+                Self {
+                    name: module_name.to_string(),
+                    destination_modules,
+                    module_type: RefCell::new(ModuleType::Conjunction(conjunction_map))
+                }
+            },
+            _ => Self {
+                name: "broadcaster".to_string(),
+                destination_modules: destination_modules.iter().map(|s| s.to_string()).collect(),
+                module_type: RefCell::new(ModuleType::Untyped)
+            },
+        };
+        Ok(module)
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self.module_type.borrow() {
+            ModuleType::FlipFlop(_) => write!(f, "%{}", self.name)?,
+            ModuleType::Conjunction(_) => write!(f, "&{}", self.name)?,
+            ModuleType::Broadcast | ModuleType::Button | ModuleType::Untyped => write!(f, "{}", self.name)?,
+        }
+
+        write!(f, " -> {}", self.destination_modules.join(", "))
+    }
+}
@@ -0,0 +1,41 @@
+use rand::Rng;
+use aoc_core::{Generated, Grid, Position};
+
+// A map of `size` to `2 × size + 1` rows and columns with the start anywhere, where about one tile in
+// 8 is a rock. Part 2 can only walk it garden by garden, for a few steps.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let sides = size.max(1)..=2 * size.max(1) + 1;
+    let (rows, cols) = (rng.gen_range(sides.clone()), rng.gen_range(sides));
+    let mut map = Grid::new(rows, cols, '.');
+
+    for position in map.positions().collect::<Vec<Position>>() {
+        if rng.gen_bool(0.125) {
+            map[position] = '#';
+        }
+    }
+    map[Position::new(rng.gen_range(0..rows), rng.gen_range(0..cols))] = 'S';
+
+    Generated::new(map.to_string())
+}
+
+// A `2 × size + 1` square map with the start in the middle, where about one tile in 8 is a rock
+// except on the middle row and column, the border and the diamond between their ends, like in the
+// puzzle. The long walk of part 2 relies on that layout, and its 26501365 steps only end on the
+// border of a map for the sides dividing 53002731, like 131.
+pub fn puzzle_like<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let half = size.max(1);
+    let side = 2 * half + 1;
+    let mut map = Grid::new(side, side, '.');
+
+    for position in map.positions().collect::<Vec<Position>>() {
+        let (row, col) = (position.row.abs_diff(half), position.col.abs_diff(half));
+        let clear = row == 0 || col == 0 || row == half || col == half || row + col == half;
+
+        if !clear && rng.gen_bool(0.125) {
+            map[position] = '#';
+        }
+    }
+    map[Position::new(half, half)] = 'S';
+
+    Generated::new(map.to_string())
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, fmt};
+use aoc_core::{parse, search, Answer, Grid, Parameters, Params, ParseError, Position};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Gardens, ParseError> {
+    let nodes: Grid<NodeType> = input.parse()?;
+
+    let start: Position = nodes
+        .find(|node_type| node_type == &NodeType::Start)
+        .ok_or_else(|| ParseError::at(input, "expected a starting position `S`"))?;
+
+    Ok(Gardens::new(nodes, start))
+}
+
+pub fn render(gardens: &Gardens) -> String {
+    gardens.gardens.to_string()
+}
+
+pub fn part1(gardens: &Gardens, config: Part1Config) -> Answer {
+    gardens.walk(config.steps).into()
+}
+
+pub fn part2(gardens: &Gardens, config: Part2Config) -> Result<Answer, ParseError> {
+    gardens.walk_infinite(config.steps).map(Answer::from)
+}
+
+// Beyond that, walking the repeated map garden by garden takes too long
+const MAX_TILED_STEPS: usize = 1000;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part1Config {
+    pub steps: usize,
+}
+
+impl Default for Part1Config {
+    fn default() -> Self {
+        Self { steps: 64 }
+    }
+}
+
+impl Parameters for Part1Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        Ok(Self { steps: params.take("steps", Self::default().steps)? })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part2Config {
+    pub steps: usize,
+}
+
+impl Default for Part2Config {
+    fn default() -> Self {
+        Self { steps: 26_501_365 }
+    }
+}
+
+impl Parameters for Part2Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        Ok(Self { steps: params.take("steps", Self::default().steps)? })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum NodeType {
+    Rock,
+    Garden,
+    Start,
+}
+
+impl TryFrom<char> for NodeType {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(NodeType::Rock),
+            '.' => Ok(NodeType::Garden),
+            'S' => Ok(NodeType::Start),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeType::Rock => write!(f, "#"),
+            NodeType::Garden => write!(f, "."),
+            NodeType::Start => write!(f, "S"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gardens {
+    gardens: Grid<NodeType>,
+    start: Position,
+}
+
+impl Gardens {
+    fn new(gardens: Grid<NodeType>, start: Position) -> Self {
+        Self {
+            gardens,
+            start,
+        }
+    }
+
+    fn walk(&self, steps: usize) -> usize {
+        reachable(&self.distances(), steps)
+    }
+
+    // Same walk on the map repeated infinitely in every direction. Past a few maps, the count for
+    // the steps going one more map away grows like a square: the gardens reached are those of whole
+    // maps, whose number grows like a square, and those of the maps at the edge of the walk, whose
+    // number grows by the same amount each time. That only holds when every map is first entered
+    // at the same cells, in the middle of its sides or at its corners, like the puzzle's own
+    // inputs which have nothing in the way along the middle row and column and along the border.
+    fn walk_infinite(&self, steps: usize) -> Result<usize, ParseError> {
+        let Some(fill) = self.fill_steps() else {
+            if steps > MAX_TILED_STEPS {
+                return Err(ParseError::new(format!(
+                    "walking more than {} steps needs a square map with the start in the middle and no rock on its middle row, middle column and border",
+                    MAX_TILED_STEPS,
+                )));
+            }
+            return Ok(reachable(&self.tiled_distances(steps), steps));
+        };
+
+        // Four counts from where the edge of the walk is settled, a map apart, the last one checks
+        // that they grow like a square
+        let side = self.gardens.rows();
+        let first = fill / side + 2;
+        let samples: [usize; 4] = [0, 1, 2, 3].map(|i| steps % side + (first + i) * side);
+        if steps <= samples[3] {
+            return Ok(reachable(&self.tiled_distances(steps), steps));
+        }
+
+        let distances = self.tiled_distances(samples[3]);
+        let [a, b, c, d] = samples.map(|steps| reachable(&distances, steps) as i128);
+        let growth = c - 2 * b + a;
+        if d - 2 * c + b != growth {
+            return Err(ParseError::new("the reachable gardens do not grow like a square"));
+        }
+
+        // a, b, c, ... are the values of `a + (b - a) n + growth n (n - 1) / 2`
+        let n = ((steps - samples[0]) / side) as i128;
+        n.checked_mul(b - a)
+            .and_then(|linear| n.checked_mul(n - 1)?.checked_mul(growth).map(|square| (linear, square / 2)))
+            .and_then(|(linear, square)| a.checked_add(linear)?.checked_add(square))
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(|| ParseError::new("more gardens are reachable than fit in a usize"))
+    }
+
+    // Steps taken to reach every garden of a map from any cell it can be entered by, if the layout
+    // makes the walk on the repeated map enter every map by the middle of its sides or its corners
+    fn fill_steps(&self) -> Option<usize> {
+        let side = self.gardens.rows();
+        let half = side / 2;
+        let clear = |position: Position| self.gardens[position] != NodeType::Rock;
+        let last = side.checked_sub(1)?;
+
+        let centred = side == self.gardens.cols() && side % 2 == 1 && self.start == Position::new(half, half);
+        let lanes = || (0..side).all(|i| {
+            [(half, i), (i, half), (0, i), (last, i), (i, 0), (i, last)]
+                .into_iter()
+                .all(|(row, col)| clear(Position::new(row, col)))
+        });
+        if !centred || !lanes() {
+            return None;
+        }
+
+        [0, half, last]
+            .into_iter()
+            .flat_map(|row| [0, half, last].map(|col| Position::new(row, col)))
+            .map(|entry| self.distances_from(entry).into_values().max().unwrap_or(0))
+            .max()
+    }
+
+    // Distances from the start on the repeated map, up to `steps`
+    fn tiled_distances(&self, steps: usize) -> HashMap<(i64, i64), usize> {
+        let (rows, cols) = (self.gardens.rows() as i64, self.gardens.cols() as i64);
+        let start = (self.start.row as i64, self.start.col as i64);
+        let gardens = &self.gardens;
+
+        // No garden farther than `steps` as the crow flies can be reached, which bounds the search
+        let near = move |(row, col): (i64, i64)| row.abs_diff(start.0) + col.abs_diff(start.1) <= steps as u64;
+        let garden = move |(row, col): (i64, i64)| {
+            gardens[Position::new(row.rem_euclid(rows) as usize, col.rem_euclid(cols) as usize)] != NodeType::Rock
+        };
+
+        search::bfs(start, |&(row, col)| {
+            [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)]
+                .into_iter()
+                .filter(move |&neighbour| near(neighbour) && garden(neighbour))
+        })
+    }
+
+    fn distances(&self) -> HashMap<Position, usize> {
+        self.distances_from(self.start)
+    }
+
+    fn distances_from(&self, start: Position) -> HashMap<Position, usize> {
+        let gardens = &self.gardens;
+
+        search::bfs(start, |&position| {
+            gardens.neighbours4(position).filter(move |neighbour| gardens[*neighbour] != NodeType::Rock)
+        })
+    }
+}
+
+// A garden can be reached in exactly `steps` if it is not farther than that and the distance has the
+// same parity, the elf can always step back and forth to waste the remaining steps
+fn reachable<P>(distances: &HashMap<P, usize>, steps: usize) -> usize {
+    distances
+        .values()
+        .filter(|distance| **distance <= steps && **distance % 2 == steps % 2)
+        .count()
+}
+
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+use rand::Rng;
+use aoc_core::Generated;
+
+// `size` bricks of 1 to 4 cubes floating over the 10 × 10 ground, none of them overlapping
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let height = size as u32 / 4 + 4;
+    let mut occupied: HashSet<[u32; 3]> = HashSet::new();
+    let mut input = String::new();
+    let mut bricks: usize = 0;
+
+    while bricks < size {
+        let axis = rng.gen_range(0..3);
+        let length = rng.gen_range(1..=4);
+
+        let mut start = [rng.gen_range(0..10), rng.gen_range(0..10), rng.gen_range(1..=height)];
+        start[axis] = start[axis].min(if axis == 2 { height + 1 } else { 10 } - length);
+        let mut end = start;
+        end[axis] += length - 1;
+
+        let cubes: Vec<[u32; 3]> = (0..length)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+
+        occupied.extend(cubes);
+        bricks += 1;
+        input.push_str(&format!("{},{},{}~{},{},{}\n", start[0], start[1], start[2], end[0], end[1], end[2]));
+    }
+
+    Generated::new(input)
+}
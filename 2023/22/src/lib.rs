@@ -1,60 +1,45 @@
-use std::{fs, cell::RefCell, collections::{HashMap, HashSet}, ops::Range};
+use std::{cell::RefCell, collections::{HashMap, HashSet}, fmt, ops::Range};
+use std::str::FromStr;
+use aoc_core::{parse, Answer, ParseError};
 
-fn main() {
-    let input: Vec<Block> = fs::read_to_string("input.txt").unwrap()
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+    let blocks: Vec<Block> = input
         .split('\n')
         .filter(|line| !line.is_empty())
-        .map(|line| line
-            .split('~')
-            .map(|part| part
-                .split(',')
-                .map(|value| value.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>()
-            )
-            .collect::<Vec<Vec<usize>>>()
-            .into_iter()
-            .map(|values| (values[0], values[1], values[2]))
-            .collect::<Vec<(usize, usize, usize)>>()
-        )
-        .collect::<Vec<Vec<(usize, usize, usize)>>>()
-        .into_iter()
-        .map(|v| (v[0], v[1]).into())
-        .collect();
-
-    let sand_blocks: SandBlocks = SandBlocks::new(input);
-
-    sand_blocks.sort();
-    sand_blocks.name_blocks();
-
-    // println!("{:?}", sand_blocks);
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    if blocks.is_empty() {
+        return Err(ParseError::at(input, "expected at least one brick"));
+    }
 
-    // println!("{:?}", sand_blocks.get_boundary_coordinates());
+    Ok(blocks)
+}
 
-    sand_blocks.fall();
+pub fn render(blocks: &[Block]) -> String {
+    blocks.iter().map(|block| format!("{}\n", block)).collect()
+}
 
-    // println!("{:?}", sand_blocks);
+pub fn part1(blocks: &[Block]) -> Answer {
+    let sand_blocks: SandBlocks = settle(blocks);
 
     let supports = sand_blocks.blocks_supports();
     let is_supported_by = sand_blocks.blocks_supported_by();
 
-    // println!("Supports: {:?}", supports.get(&477).unwrap());
-    // println!("{}", "-".repeat(40));
-    // println!("Is Supported: {:?}", is_supported_by.get(&477).unwrap());
+    let safe_to_remove: HashSet<usize> = safe_to_remove(&supports, &is_supported_by);
+    tracing::debug!(bricks = blocks.len(), safe = safe_to_remove.len(), "bricks safe to disintegrate");
 
-    let mut safe_to_remove: HashSet<usize> = HashSet::new();
+    safe_to_remove.len().into()
+}
 
-    for (block, supported_blocks) in supports.clone() {
-        if supported_blocks.is_empty() {
-            safe_to_remove.insert(block);
-        }
+pub fn part2(blocks: &[Block]) -> Answer {
+    let sand_blocks: SandBlocks = settle(blocks);
 
-        if supported_blocks.iter().all(|b| is_supported_by.get(b).unwrap().len() > 1) {
-            safe_to_remove.insert(block);
-        }
-    }
+    let supports = sand_blocks.blocks_supports();
+    let is_supported_by = sand_blocks.blocks_supported_by();
 
-    // println!("{:?}", safe_to_remove);
-    // println!("{:?}", safe_to_remove.len());
+    let safe_to_remove: HashSet<usize> = safe_to_remove(&supports, &is_supported_by);
 
     let unsafe_to_remove: HashSet<usize> = sand_blocks.blocks.borrow()
         .iter()
@@ -64,19 +49,13 @@ fn main() {
 
     let mut sum: usize = 0;
 
-    // let unsafe_to_remove = HashSet::from([278]);
-
     for u in unsafe_to_remove.iter() {
         let mut falls: HashSet<usize> = HashSet::new();
         let mut to_check: Vec<usize> = vec![*u];
 
         while let Some(block) = to_check.pop() {
-            // println!("To Check: {:?}", block);
-            // println!("{}", "-".repeat(40));
             let blocks_that_would_fall = supports.get(&block).unwrap();
             for b in blocks_that_would_fall {
-                // println!("Block: {:?}", b);
-                // println!("Supported by: {:?}", is_supported_by.get(b).unwrap());
                 if is_supported_by.get(b).unwrap().iter().filter(|b| !falls.contains(b) && *b != u).collect::<Vec<_>>().is_empty(){
                     falls.insert(*b);
                     to_check.push(*b);
@@ -84,14 +63,48 @@ fn main() {
             }
         }
 
+        tracing::trace!(block = u, falls = falls.len(), "chain reaction");
         sum += falls.len();
     }
 
-    println!("{:?}", unsafe_to_remove);
-    println!("{:?}", sum);
+    sum.into()
+}
+
+// The bricks once they have all fallen
+#[cfg(feature = "serde")]
+pub fn solved(blocks: &[Block]) -> impl serde::Serialize {
+    settle(blocks)
+}
+
+fn settle(blocks: &[Block]) -> SandBlocks {
+    let sand_blocks: SandBlocks = SandBlocks::new(blocks.to_vec());
+
+    sand_blocks.sort();
+    sand_blocks.name_blocks();
+    sand_blocks.fall();
+
+    sand_blocks
+}
+
+// A block can be removed when every block it supports rests on at least another one
+fn safe_to_remove(supports: &HashMap<usize, Vec<usize>>, is_supported_by: &HashMap<usize, Vec<usize>>) -> HashSet<usize> {
+    let mut safe_to_remove: HashSet<usize> = HashSet::new();
+
+    for (block, supported_blocks) in supports {
+        if supported_blocks.is_empty() {
+            safe_to_remove.insert(*block);
+        }
+
+        if supported_blocks.iter().all(|b| is_supported_by.get(b).unwrap().len() > 1) {
+            safe_to_remove.insert(*block);
+        }
+    }
+
+    safe_to_remove
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Coordinate {
     X,
     Y,
@@ -110,6 +123,7 @@ impl From<char> for Coordinate {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Position {
     x: usize,
     y: usize,
@@ -122,47 +136,79 @@ impl From<(usize, usize, usize)> for Position {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values: Vec<&str> = s.split(',').collect();
+        let [x, y, z] = values[..] else {
+            return Err(ParseError::at(s, format!("expected `<x>,<y>,<z>`, found `{}`", s)));
+        };
+
+        Ok(Self { x: parse::number(x)?, y: parse::number(y)?, z: parse::number(z)? })
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Orientation {
     Vertical,
     HorizontalX,
     HorizontalY,
 }
 
-impl From<(&Position, &Position)> for Orientation {
-    fn from((start, end): (&Position, &Position)) -> Self {
+impl TryFrom<(&Position, &Position)> for Orientation {
+    type Error = ParseError;
+
+    fn try_from((start, end): (&Position, &Position)) -> Result<Self, Self::Error> {
         if start.x == end.x && start.y == end.y {
-            Self::Vertical
+            Ok(Self::Vertical)
         } else if start.z == end.z && start.y == end.y {
-            Self::HorizontalX
+            Ok(Self::HorizontalX)
         } else if start.z == end.z && start.x == end.x {
-            Self::HorizontalY
+            Ok(Self::HorizontalY)
         } else {
-            unreachable!()
+            Err(ParseError::new("expected the ends of a brick to differ along a single axis"))
         }
     }
 }
 
-#[derive(Debug, Clone)]
-struct Block {
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
     id: usize,
     start: Position,
     end: Position,
     orientation: Orientation
 }
 
-impl From<((usize, usize, usize), (usize, usize, usize))> for Block {
-    fn from((start, end): ((usize, usize, usize), (usize, usize, usize))) -> Self {
-        let start: Position = start.into();
-        let end: Position = end.into();
-        let orientation: Orientation = (&start, &end).into();
+impl FromStr for Block {
+    type Err = ParseError;
 
-        Self {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = parse::split_once(s, "~")?;
+        let start: Position = start.parse()?;
+        let end: Position = end.parse()?;
+        let orientation: Orientation = Orientation::try_from((&start, &end)).map_err(|e| e.with_location(s))?;
+
+        Ok(Self {
             id: 0,
             start,
             end,
             orientation,
-        }
+        })
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}~{}", self.start, self.end)
     }
 }
 
@@ -203,6 +249,7 @@ impl Block {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct SandBlocks {
     blocks: RefCell<Vec<Block>>,
     blocks_after_falling: RefCell<HashMap<usize, Vec<Vec<usize>>>>,
@@ -226,13 +273,10 @@ impl SandBlocks {
     }
 
     fn name_blocks(&self) {
-        let mut start: usize = 1;
-
         let mut named_blocks: Vec<Block> = vec![];
 
-        for block in self.blocks.borrow().iter() {
-            named_blocks.push(Block { id: start, start: block.start, end: block.end, orientation: block.orientation });
-            start += 1;
+        for (id, block) in (1..).zip(self.blocks.borrow().iter()) {
+            named_blocks.push(Block { id, start: block.start, end: block.end, orientation: block.orientation });
         }
 
         *self.blocks.borrow_mut() = named_blocks;
@@ -264,18 +308,13 @@ impl SandBlocks {
         }
 
         for block in self.blocks.borrow().iter() {
-            // println!("{:?}", block);
             let mut z_with_space = z_range.end - 1;
 
             for z in z_range.clone().rev() {
-                // println!("Z: {:?}", z);
-                // println!("{:?}", map.get(&z).unwrap());
                 let mut has_space = true;
 
                 for x in block.range(&Coordinate::X) {
                     for y in block.range(&Coordinate::Y) {
-                        // println!("{} {}", x, y);
-                        // println!("{:?}", map.get(&(z)).unwrap()[x][y]);
                         if map.get(&(z)).unwrap()[x][y] != 0 {
                             has_space = false;
                             break;
@@ -287,7 +326,6 @@ impl SandBlocks {
                 }
 
                 if has_space {
-                    // println!("Has Space: {:?}", z);
                     z_with_space = z;
                 } else {
                     break;
@@ -309,9 +347,8 @@ impl SandBlocks {
                 orientation: block.orientation
             });
 
-            // println!("Block: {:?}", block);
+            tracing::trace!(block = block.id, from = block.start.z, to = z_with_space, "brick fell");
             for z in z_with_space..(block.range(&Coordinate::Z).len() + z_with_space) {
-                // println!("Z: {:?}", z);
                 for x in block.range(&Coordinate::X) {
                     for y in block.range(&Coordinate::Y) {
                         map.get_mut(&z).unwrap()[x][y] = block.id;
@@ -321,24 +358,6 @@ impl SandBlocks {
         }
 
         *self.blocks.borrow_mut() = new_blocks;
-
-
-        // for z in z_range {
-        //     let row = map.get(&z).unwrap();
-        //
-        //     if row.iter().any(|col| col.iter().any(|c| *c != 0)) {
-        //         println!("Z: {:?}", z);
-        //         println!("{}", "-".repeat(40));
-        //         for col in row.iter() {
-        //             for c in col {
-        //                 let w: usize = 4;
-        //                 print!("{c:>w$} ");
-        //             }
-        //             println!();
-        //         }
-        //         println!();
-        //     }
-        // }
     }
 
     fn blocks_supports(&self) -> HashMap<usize, Vec<usize>> {
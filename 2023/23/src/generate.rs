@@ -0,0 +1,46 @@
+use rand::Rng;
+use aoc_core::{Generated, Grid, Position};
+
+// A forest crossed by the paths of a `size` × `size` grid of crossings, like in the puzzle: the
+// paths between neighbouring crossings are 2 to 7 tiles long and slope right or down at both ends,
+// so that part 1 only goes right or down from a crossing. The start is above the first crossing
+// and the end below the last one.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let crossings = size.max(1);
+    let rows = lines(rng, crossings);
+    let cols = lines(rng, crossings);
+
+    let (last_row, last_col) = (rows[crossings - 1] + 1, cols[crossings - 1]);
+    let mut map = Grid::new(last_row + 1, last_col + 2, '#');
+    map[Position::new(0, cols[0])] = '.';
+    map[Position::new(last_row, last_col)] = '.';
+
+    for (i, &row) in rows.iter().enumerate() {
+        for (j, &col) in cols.iter().enumerate() {
+            map[Position::new(row, col)] = '.';
+
+            if let Some(&next) = cols.get(j + 1) {
+                for col in col + 1..next {
+                    map[Position::new(row, col)] = if col == cols[j] + 1 || col == next - 1 { '>' } else { '.' };
+                }
+            }
+            if let Some(&next) = rows.get(i + 1) {
+                for row in row + 1..next {
+                    map[Position::new(row, col)] = if row == rows[i] + 1 || row == next - 1 { 'v' } else { '.' };
+                }
+            }
+        }
+    }
+
+    Generated::new(map.to_string())
+}
+
+// The rows (or columns) of the crossings
+fn lines<R: Rng>(rng: &mut R, crossings: usize) -> Vec<usize> {
+    let mut lines: Vec<usize> = vec![1];
+    for _ in 1..crossings {
+        lines.push(lines[lines.len() - 1] + rng.gen_range(3..=8));
+    }
+
+    lines
+}
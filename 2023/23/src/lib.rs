@@ -0,0 +1,307 @@
+use std::collections::BinaryHeap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use aoc_core::{parse, search, Answer, Direction, Frame, Grid, ParseError, Position};
+use aoc_core::visual::{Cell, Color};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+pub fn render(map: &Map) -> String {
+    map.to_string()
+}
+
+pub fn part1(map: &Map) -> Answer {
+    let result = map.walk_longest_path();
+
+    result.1.into()
+}
+
+// Without the slopes, the paths between crossings are collapsed into the edges of a graph
+pub fn part2(map: &Map) -> Answer {
+    let (start, end) = map.get_start_and_end();
+
+    let graph: Graph = map.into();
+    let result = graph.walk_longest_path(start, end);
+
+    result.1.into()
+}
+
+// The longest hike, part 2 only knows the crossings it goes through
+pub fn frames(map: &Map, part: u8) -> Vec<Frame> {
+    let (path, steps) = match part {
+        1 => map.walk_longest_path(),
+        _ => {
+            let (start, end) = map.get_start_and_end();
+            Graph::from(map).walk_longest_path(start, end)
+        }
+    };
+    let path: HashSet<Position> = path.into_iter().collect();
+
+    let frame = Frame::from_grid(&map.map, |position, tile| match tile {
+        _ if path.contains(&position) => Cell::new('O', Color::RED),
+        TileType::Forest => Cell::new(tile.glyph(), Color::GREEN.mix(Color::BLACK, 0.5)),
+        _ => Cell::new(tile.glyph(), Color::WHITE),
+    });
+
+    vec![frame.with_caption(format!("{} steps", steps))]
+}
+
+// Slope
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Slope {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+impl From<char> for Slope {
+    fn from(c: char) -> Self {
+        match c {
+            '^' => Slope::Up,
+            '<' => Slope::Left,
+            'v' => Slope::Down,
+            '>' => Slope::Right,
+            _ => unreachable!()
+        }
+    }
+}
+
+impl From<Slope> for Direction {
+    fn from(slope: Slope) -> Self {
+        match slope {
+            Slope::Up => Direction::Up,
+            Slope::Left => Direction::Left,
+            Slope::Down => Direction::Down,
+            Slope::Right => Direction::Right,
+        }
+    }
+}
+
+// TileType
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TileType {
+    Path,
+    Forest,
+    Slope(Slope)
+}
+
+impl TryFrom<char> for TileType {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(TileType::Path),
+            '#' => Ok(TileType::Forest),
+            '>' | '<' | '^' | 'v' => Ok(TileType::Slope(c.into())),
+            _ => Err(parse::unexpected(c))
+        }
+    }
+}
+
+impl TileType {
+    fn glyph(&self) -> char {
+        match self {
+            TileType::Path => '.',
+            TileType::Forest => '#',
+            TileType::Slope(Slope::Up) => '^',
+            TileType::Slope(Slope::Left) => '<',
+            TileType::Slope(Slope::Down) => 'v',
+            TileType::Slope(Slope::Right) => '>',
+        }
+    }
+}
+
+// Map
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map {
+    map: Grid<TileType>,
+}
+
+impl Map {
+    fn get_start_and_end(&self) -> (Position, Position) {
+        let last_row = self.map.rows() - 1;
+        let start = Position::new(0, self.map.row(0).iter().position(|t| t == &TileType::Path).unwrap());
+        let end = Position::new(last_row, self.map.row(last_row).iter().position(|t| t == &TileType::Path).unwrap());
+
+        (start, end)
+    }
+
+    fn walk_longest_path(&self) -> (Vec<Position>, usize) {
+        let (start, end) = self.get_start_and_end();
+
+        let successors = |&position: &Position| {
+            match self.map[position] {
+                TileType::Slope(slope) => {
+                    self.map.step(position, slope.into(), 1).into_iter().collect()
+                },
+                _ => {
+                    self.map.neighbours4(position).collect::<Vec<Position>>()
+                }
+            }
+            .into_iter()
+            .filter(|p| self.map[*p] != TileType::Forest)
+            .map(|p| (p, 1))
+        };
+
+        search::longest_path(start, successors, |position| *position == end)
+            .map_or((vec![], 0), |path| (path.states, path.cost))
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        if let Some(line) = lines.iter().find(|line| line.len() != lines[0].len()) {
+            return Err(ParseError::at(line, format!("expected {} columns, found {}", lines[0].len(), line.len())));
+        }
+        for line in [lines.first(), lines.last()] {
+            if !line.is_some_and(|line| line.contains('.')) {
+                return Err(ParseError::at(line.unwrap_or(&s), "expected a path tile in the first and last rows"));
+            }
+        }
+
+        let map: Grid<TileType> = s.parse()?;
+
+        Ok(Map { map })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.map.iter_rows() {
+            writeln!(f, "{}", row.iter().map(TileType::glyph).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// Edge
+// -----------------------------------------------------------------------------
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Edge {
+    p1: Position,
+    p2: Position,
+    distance: usize
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Node
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    #[allow(dead_code)]
+    position: Position,
+    #[cfg_attr(feature = "serde", serde(with = "aoc_core::canonical::pairs"))]
+    edges: HashMap<Position, usize>
+}
+
+// Graph
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Graph {
+    #[cfg_attr(feature = "serde", serde(with = "aoc_core::canonical::pairs"))]
+    nodes: HashMap<Position, Node>
+}
+
+impl From<Vec<Edge>> for Graph {
+    fn from(v: Vec<Edge>) -> Self {
+        let mut nodes: HashMap<Position, Node> = HashMap::new();
+
+        for edge in v {
+            if edge.distance == 0 { continue; }
+            nodes
+                .entry(edge.p1)
+                .and_modify(|e| { e.edges.insert(edge.p2, edge.distance); })
+                .or_insert(Node { position: edge.p1, edges: HashMap::from([(edge.p2, edge.distance)]) });
+        }
+
+        Graph { nodes }
+    }
+}
+
+impl From<&Map> for Graph {
+    fn from(m: &Map) -> Self {
+        let start = Position::new(0, m.map.row(0).iter().position(|t| t == &TileType::Path).unwrap());
+
+        let mut edges: Vec<Edge> = Vec::new();
+        let mut visited: Vec<(Position, Position)> = vec![];
+        let mut heap: BinaryHeap<Edge> = BinaryHeap::new();
+
+        edges.push(Edge { p1: start, p2: start, distance: 0 });
+        heap.push(Edge { p1: start, p2: start, distance: 0 });
+
+        while let Some(Edge { p1, p2, distance }) = heap.pop() {
+            let mut neighbours: Vec<Position> = m.map.neighbours4(p2)
+                .filter(|p| m.map[*p] != TileType::Forest)
+                .filter(|p| !visited.contains(&(*p, p2)))
+                .collect();
+
+            let mut next_edge: Edge = Edge { p1, p2, distance };
+
+            while neighbours.len() == 1 {
+                let last_position = next_edge.p2;
+                next_edge.p2 = neighbours[0];
+                next_edge.distance += 1;
+                neighbours = m.map.neighbours4(next_edge.p2)
+                    .filter(|p| m.map[*p] != TileType::Forest)
+                    .filter(|p| *p != last_position)
+                    .collect();
+            }
+
+            edges.push(next_edge);
+
+
+            for neighbour in neighbours {
+                if !visited.contains(&(next_edge.p2, neighbour)) {
+                    let next = Edge {
+                        p1: next_edge.p2,
+                        p2: neighbour,
+                        distance: 1,
+                    };
+                    heap.push(next);
+                    visited.push((next_edge.p2, neighbour));
+                }
+            }
+        }
+        edges.into()
+    }
+}
+
+impl Graph {
+    fn walk_longest_path(&self, start: Position, end: Position) -> (Vec<Position>, usize) {
+        let successors = |position: &Position| {
+            self.nodes[position].edges.iter().map(|(neighbour, distance)| (*neighbour, *distance))
+        };
+
+        search::longest_path(start, successors, |position| *position == end)
+            .map_or((vec![], 0), |path| (path.states, path.cost))
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::Generated;
+
+// `size` hailstones (50 at least, 100000 at most) as far out as in the puzzle, all hit by a rock
+// thrown from a random position at integer times, with any speed up to 1000 on every axis. The
+// position of the rock is known. Part 2 finds the rock from the hailstones sharing a speed on an
+// axis, which these may well not have enough of.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let speeds: Vec<i64> = (-1000..=1000).collect();
+
+    hailstones(rng, size.clamp(50, 100_000), |_| speeds.clone())
+}
+
+// Same as `input` with only about `2 × √size` speeds on each axis, all faster than the 100 part 2
+// only looks at, like in the puzzle
+pub fn puzzle_like<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let size = size.clamp(50, 100_000);
+
+    hailstones(rng, size, |rng| {
+        let mut speeds: Vec<i64> = (101..=900).flat_map(|speed| [speed, -speed]).collect();
+        speeds.shuffle(rng);
+        speeds.truncate((2.0 * (size as f64).sqrt()) as usize);
+        speeds
+    })
+}
+
+// The hailstones take their speed on each axis from the ones `speeds` draws for it
+fn hailstones<R: Rng>(rng: &mut R, size: usize, mut speeds: impl FnMut(&mut R) -> Vec<i64>) -> Generated {
+    let rock: [i64; 3] = [(); 3].map(|_| rng.gen_range(200_000_000_000_000..300_000_000_000_000));
+    let throw: [i64; 3] = [(); 3].map(|_| rng.gen_range(-300..=300));
+
+    let speeds: Vec<Vec<i64>> = throw
+        .iter()
+        .map(|&throw| speeds(rng).into_iter().filter(|&speed| speed != throw).collect())
+        .collect();
+
+    let mut times: HashSet<i64> = HashSet::new();
+    let mut directions: HashSet<(i64, i64)> = HashSet::new();
+    let mut input = String::new();
+
+    while times.len() < size {
+        let time = rng.gen_range(10_000_000_000..200_000_000_000);
+        let velocity: [i64; 3] = [0, 1, 2].map(|axis| *speeds[axis].choose(rng).unwrap());
+
+        // The rock moves along a different line than every other hailstone seen from above
+        let relative = (velocity[0] - throw[0], velocity[1] - throw[1]);
+        let divisor = gcd(relative.0, relative.1) * relative.0.signum();
+        if times.contains(&time) || !directions.insert((relative.0 / divisor, relative.1 / divisor)) {
+            continue;
+        }
+        times.insert(time);
+
+        let position: [i64; 3] = [0, 1, 2].map(|axis| rock[axis] + time * (throw[axis] - velocity[axis]));
+        input.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2],
+        ));
+    }
+
+    Generated::new(input).with_answer(2, rock.iter().sum::<i64>())
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
@@ -0,0 +1,274 @@
+use std::{collections::HashSet, fmt, ops::RangeInclusive, str::FromStr};
+use aoc_core::{math::{self, Intersection, Ratio}, parse, Answer, Parameters, Params, ParseError};
+
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+pub fn render(hailstones: &[Hailstone]) -> String {
+    hailstones.iter().map(|hailstone| format!("{}\n", hailstone)).collect()
+}
+
+pub fn part1(hailstones: &[Hailstone], config: Part1Config) -> Answer {
+    let range: RangeInclusive<Ratio> = Ratio::from(config.area_min)..=Ratio::from(config.area_max);
+    let mut sum: i32 = 0;
+
+    for i in 0..hailstones.len() {
+        for j in i+1..hailstones.len() {
+            if let Some(crossing) = hailstones[i].intersects_at_xy(&hailstones[j]) {
+                let inside = range.contains(&crossing.x) && range.contains(&crossing.y);
+                tracing::trace!(i, j, x = %crossing.x, y = %crossing.y, inside, "paths cross");
+
+                if inside {
+                    sum += 1;
+                }
+            }
+        }
+    }
+
+    sum.into()
+}
+
+pub fn part2(hailstones: &[Hailstone], config: Part2Config) -> Result<Answer, ParseError> {
+    if hailstones.len() < 2 {
+        return Err(ParseError::new("expected at least two hailstones"));
+    }
+
+    // Based on https://www.reddit.com/r/adventofcode/comments/18pnycy/comment/keqf8uq/
+    let mut hailstones: Vec<Hailstone> = hailstones.to_vec();
+    hailstones.sort();
+
+    let vx = rock_velocities(&hailstones, "x", |h| &h.x, config)?;
+    let vy = rock_velocities(&hailstones, "y", |h| &h.y, config)?;
+    let vz = rock_velocities(&hailstones, "z", |h| &h.z, config)?;
+
+    // A few velocities may be left on an axis, only one throw should hit every hailstone
+    let mut throws: Vec<[i128; 3]> = vec![];
+    for &vx in &vx {
+        for &vy in &vy {
+            for &vz in &vz {
+                throws.extend(throw(&hailstones, [vx, vy, vz]));
+            }
+        }
+    }
+
+    let position = match throws[..] {
+        [position] => position,
+        [] => return Err(ParseError::new("no rock velocity left hits every hailstone")),
+        _ => return Err(ParseError::new(format!("{} rock throws hit every hailstone, expected one", throws.len()))),
+    };
+
+    position
+        .iter()
+        .try_fold(0_i128, |sum, coordinate| sum.checked_add(coordinate.checked_abs()?))
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::new("the rock starts too far away"))
+}
+
+// The rock velocities on an axis that let the rock hit every pair of hailstones moving as fast on
+// it: the distance between them must be a multiple of the speed of the rock relative to them
+fn rock_velocities(
+    hailstones: &[Hailstone],
+    axis: &str,
+    coefficients: impl Fn(&Hailstone) -> &Coefficients,
+    config: Part2Config,
+) -> Result<Vec<i64>, ParseError> {
+    let velocities = config
+        .max_velocity
+        .checked_neg()
+        .map(|min| min..=config.max_velocity)
+        .ok_or_else(|| ParseError::new("max_velocity is out of range"))?;
+    let mut candidates: Option<HashSet<i64>> = None;
+
+    for i in 0..hailstones.len() {
+        for j in i+1..hailstones.len() {
+            let (a, b) = (coefficients(&hailstones[i]), coefficients(&hailstones[j]));
+            if a.a1 != b.a1 || i128::from(a.a1).abs() <= i128::from(config.min_speed) {
+                continue;
+            }
+
+            let distance = i128::from(a.a0) - i128::from(b.a0);
+            let fitting: HashSet<i64> = velocities
+                .clone()
+                .filter(|v| *v != a.a1 && distance % (i128::from(*v) - i128::from(a.a1)) == 0)
+                .collect();
+
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&fitting).copied().collect(),
+                None => fitting,
+            });
+        }
+    }
+
+    let mut candidates: Vec<i64> = candidates
+        .ok_or_else(|| ParseError::new(format!("no pair of hailstones narrows the rock velocity on {}", axis)))?
+        .into_iter()
+        .collect();
+    if candidates.is_empty() {
+        return Err(ParseError::new(format!("no rock velocity on {} hits every pair of hailstones", axis)));
+    }
+    candidates.sort_unstable();
+
+    Ok(candidates)
+}
+
+// Where the rock starts to hit every hailstone thrown at `velocity`, if it can
+fn throw(hailstones: &[Hailstone], [vx, vy, vz]: [i64; 3]) -> Option<[i128; 3]> {
+    let (h1, h2) = (&hailstones[0], &hailstones[1]);
+
+    // Seen from the rock the hailstones all come to its starting position, where the paths of the
+    // first two cross
+    let crossing = math::intersect(
+        (h1.x.a0, h1.y.a0),
+        (h1.x.a1.checked_sub(vx)?, h1.y.a1.checked_sub(vy)?),
+        (h2.x.a0, h2.y.a0),
+        (h2.x.a1.checked_sub(vx)?, h2.y.a1.checked_sub(vy)?),
+    )?;
+    let z = crossing.t
+        .checked_mul(Ratio::from(h1.z.a1.checked_sub(vz)?))?
+        .checked_add(Ratio::from(h1.z.a0))?;
+    let position = [crossing.x.to_integer()?, crossing.y.to_integer()?, z.to_integer()?];
+
+    hailstones
+        .iter()
+        .all(|hailstone| hits(hailstone, position, [vx, vy, vz]))
+        .then_some(position)
+}
+
+// Whether the rock meets the hailstone at the same whole time on every axis, from its start on
+fn hits(hailstone: &Hailstone, position: [i128; 3], velocity: [i64; 3]) -> bool {
+    let mut time: Option<i128> = None;
+
+    for ((coefficients, start), v) in [&hailstone.x, &hailstone.y, &hailstone.z].into_iter().zip(position).zip(velocity) {
+        let (distance, speed) = (start - i128::from(coefficients.a0), i128::from(coefficients.a1) - i128::from(v));
+        if speed == 0 {
+            if distance != 0 {
+                return false;
+            }
+            continue;
+        }
+        if distance % speed != 0 || distance / speed < 0 || time.is_some_and(|time| time != distance / speed) {
+            return false;
+        }
+        time = Some(distance / speed);
+    }
+
+    true
+}
+
+// Both X and Y of the test area span `area_min..=area_max`
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part1Config {
+    pub area_min: i64,
+    pub area_max: i64,
+}
+
+impl Default for Part1Config {
+    fn default() -> Self {
+        Self { area_min: 200_000_000_000_000, area_max: 400_000_000_000_000 }
+    }
+}
+
+impl Parameters for Part1Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+
+        Ok(Self {
+            area_min: params.take("area_min", default.area_min)?,
+            area_max: params.take("area_max", default.area_max)?,
+        })
+    }
+}
+
+// The rock velocity is searched in `-max_velocity..=max_velocity`, using only the pairs of
+// hailstones moving faster than `min_speed` on an axis
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part2Config {
+    pub max_velocity: i64,
+    pub min_speed: i64,
+}
+
+impl Default for Part2Config {
+    fn default() -> Self {
+        Self { max_velocity: 1000, min_speed: 100 }
+    }
+}
+
+impl Parameters for Part2Config {
+    fn take(params: &mut Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+
+        Ok(Self {
+            max_velocity: params.take("max_velocity", default.max_velocity)?,
+            min_speed: params.take("min_speed", default.min_speed)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Coefficients {
+    a0: i64,
+    a1: i64,
+}
+
+impl From<(i64, i64)> for Coefficients {
+    fn from((a0, a1): (i64, i64)) -> Self {
+        Coefficients { a0, a1 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hailstone {
+    x: Coefficients,
+    y: Coefficients,
+    z: Coefficients,
+}
+
+impl FromStr for Hailstone {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = parse::split_once(s, " @ ")?;
+        let (x0, y0, z0) = parse_triple(position)?;
+        let (x1, y1, z1) = parse_triple(velocity)?;
+
+        Ok(Hailstone {
+            x: (x0, x1).into(),
+            y: (y0, y1).into(),
+            z: (z0, z1).into(),
+        })
+    }
+}
+
+impl fmt::Display for Hailstone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {} @ {}, {}, {}", self.x.a0, self.y.a0, self.z.a0, self.x.a1, self.y.a1, self.z.a1)
+    }
+}
+
+fn parse_triple(s: &str) -> Result<(i64, i64, i64), ParseError> {
+    let values: Vec<&str> = s.split(',').collect();
+    let [x, y, z] = values[..] else {
+        return Err(ParseError::at(s, format!("expected `<x>, <y>, <z>`, found `{}`", s)));
+    };
+
+    Ok((parse::number(x)?, parse::number(y)?, parse::number(z)?))
+}
+
+impl Hailstone {
+    // Where the paths cross on X and Y, unless it was before the start of one of the hailstones
+    fn intersects_at_xy(&self, other: &Hailstone) -> Option<Intersection> {
+        math::intersect((self.x.a0, self.y.a0), (self.x.a1, self.y.a1), (other.x.a0, other.y.a0), (other.x.a1, other.y.a1))
+            .filter(|crossing| !crossing.t.is_negative() && !crossing.s.is_negative())
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::{BTreeMap, HashSet};
+use rand::{seq::SliceRandom, Rng};
+use aoc_core::{generate, Generated};
+
+// `size` components (8 at least) in two groups wired together by exactly 3 wires. Each group is
+// a ring where every component is also wired to the one after next, which takes 4 cuts to split,
+// with twice as many random wires again. The product of the sizes of the groups is known.
+pub fn input<R: Rng>(rng: &mut R, size: usize) -> Generated {
+    let size = size.clamp(8, 10_000);
+    let names = generate::names(rng, size, 3, |_| true);
+    let (left, right) = names.split_at(rng.gen_range(size / 3..=size - size / 3).clamp(4, size - 4));
+
+    // Kept in order so that the same seed lists the same wires
+    let mut wires: Vec<(&str, &str)> = vec![];
+    let mut seen: HashSet<(&str, &str)> = HashSet::new();
+
+    for group in [left, right] {
+        let mut pairs: Vec<(&str, &str)> = vec![];
+        for (i, a) in group.iter().enumerate() {
+            pairs.push((a, &group[(i + 1) % group.len()]));
+            pairs.push((a, &group[(i + 2) % group.len()]));
+        }
+        for _ in 0..2 * group.len() {
+            pairs.push((group.choose(rng).unwrap(), group.choose(rng).unwrap()));
+        }
+
+        for (a, b) in pairs {
+            if a != b && !seen.contains(&(b, a)) && seen.insert((a, b)) {
+                wires.push((a, b));
+            }
+        }
+    }
+    wires.extend(left.choose_multiple(rng, 3).map(String::as_str).zip(right.choose_multiple(rng, 3).map(String::as_str)));
+
+    // Every wire is listed once, on the line of either end
+    let mut lines: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(a).or_default().push(b);
+    }
+
+    let mut lines: Vec<String> = lines.into_iter().map(|(a, bs)| format!("{}: {}", a, bs.join(" "))).collect();
+    lines.shuffle(rng);
+
+    Generated::new(lines.join("\n") + "\n").with_answer(1, left.len() * right.len())
+}
@@ -1,23 +1,32 @@
-use std::{fs, collections::{HashSet, HashMap, VecDeque}};
+use std::collections::{HashSet, HashMap};
 use itertools::iproduct;
 use rand::seq::SliceRandom;
+use aoc_core::{parse, search, Answer, ParseError};
 
-fn main() {
-    let input: Vec<(String, Vec<String>)> = fs::read_to_string("input.txt").unwrap()
+pub mod generate;
+
+pub fn parse(input: &str) -> Result<Vec<(String, Vec<String>)>, ParseError> {
+    let connections: Vec<(String, Vec<String>)> = input
         .split('\n')
         .filter(|line| !line.is_empty())
-        .map(|split|
-            split
-                .split(':')
-                .map(|s| s.trim())
-                .collect::<Vec<&str>>())
-        .map(|split| (split[0].to_string(), split[1].split(' ').map(|s| s.trim().to_string()).collect::<Vec<String>>()))
-        .collect();
+        .map(parse_connections)
+        .collect::<Result<_, _>>()?;
+    if connections.is_empty() {
+        return Err(ParseError::at(input, "expected at least one component"));
+    }
 
+    Ok(connections)
+}
+
+pub fn render(connections: &[(String, Vec<String>)]) -> String {
+    connections.iter().map(|(node, connected)| format!("{}: {}\n", node, connected.join(" "))).collect()
+}
+
+pub fn part1(connections: &[(String, Vec<String>)]) -> Answer {
     let mut nodes: HashSet<&str> = HashSet::new();
     let mut edges: HashMap<&str, HashSet<String>> = HashMap::new();
 
-    for (node, connected) in &input {
+    for (node, connected) in connections {
         nodes.insert(node);
         for n in connected {
             nodes.insert(n);
@@ -64,7 +73,7 @@ fn main() {
     }
 
     let mut most_common_edges: Vec<((String, String), usize)> = most_common_edges.into_iter().collect();
-    most_common_edges.sort_by(|a, b| b.1.cmp(&a.1));
+    most_common_edges.sort_by_key(|e| std::cmp::Reverse(e.1));
 
     // The top 3 most common edges should be the ones that need to be cut to create two separate
     // groups. If it doesn't work, increase the sample.
@@ -82,45 +91,35 @@ fn main() {
         graph.add_node(Node { id: node.to_string(), neighbours: edges[node].clone() })
     }
 
-    let mut result_map: HashMap<&str, HashSet<String>> = HashMap::new();
+    // Both ends of a cut edge belong to different groups, the size of each group is the amount of
+    // nodes reachable from either end
+    let ((from, to), _) = top_3[0];
 
-    // We can find the size of each group by running bfs on each node belonging the cut edges
-    // against every other node on the graph and counting the unique nodes that appear on the
-    // results.
-    for ((from, to), _) in &top_3 {
-        for node in &nodes {
-            let bfs_result = graph.bfs(from, node);
-            for result in bfs_result {
-                result_map.entry(from).and_modify(|e| { e.insert(result.clone()); }).or_insert({
-                    let mut new = HashSet::new();
-                    new.insert(result);
-                    new
-                });
-            }
-
-            let bfs_result = graph.bfs(to, node);
-            for result in bfs_result {
-                result_map.entry(to).and_modify(|e| { e.insert(result.clone()); }).or_insert({
-                    let mut new = HashSet::new();
-                    new.insert(result);
-                    new
-                });
-            }
-        }
-    }
+    (graph.group_size(from) * graph.group_size(to)).into()
+}
 
-    for (key, value) in result_map {
-        println!("{}: {:?}", key, value.len());
+fn parse_connections(line: &str) -> Result<(String, Vec<String>), ParseError> {
+    let (node, connected) = parse::split_once(line, ":")?;
+    if node.trim().is_empty() {
+        return Err(ParseError::at(line, "expected a component name before `:`"));
     }
+    if connected.trim().is_empty() {
+        return Err(ParseError::at(connected, "expected at least one connected component"));
+    }
+
+    Ok((node.trim().to_string(), connected.split_whitespace().map(|s| s.to_string()).collect()))
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Node {
     id: String,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "aoc_core::canonical::sorted"))]
     neighbours: HashSet<String>
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Graph {
     nodes: HashMap<String, Node>
 }
@@ -134,30 +133,16 @@ impl Graph {
         self.nodes.insert(node.id.clone(), node);
     }
 
-    fn bfs(&self, from: &str, to: &str) -> Vec<String> {
-        let mut to_see: VecDeque<(&str, Vec<String>)> = VecDeque::new();
-        let mut visited: HashSet<&str> = HashSet::new();
-
-        to_see.push_back((from, vec![from.to_string()]));
-
-        while let Some((node, path)) = to_see.pop_front() {
-            visited.insert(node);
-
-            if node == to {
-                return path;
-            }
-
-            for neighbour in &self.nodes[node].neighbours {
-                if !visited.contains(neighbour.as_str()) {
-                    visited.insert(neighbour);
+    fn neighbours<'a>(&'a self, node: &str) -> impl Iterator<Item = &'a str> {
+        self.nodes[node].neighbours.iter().map(String::as_str)
+    }
 
-                    let mut new_path = path.clone();
-                    new_path.push(neighbour.clone());
+    fn bfs(&self, from: &str, to: &str) -> Vec<String> {
+        search::bfs_path(from, |node| self.neighbours(node), |node| *node == to)
+            .map_or(vec![], |path| path.into_iter().map(str::to_string).collect())
+    }
 
-                    to_see.push_back((neighbour, new_path));
-                }
-            }
-        }
-        vec![]
+    fn group_size(&self, node: &str) -> usize {
+        search::bfs(node, |node| self.neighbours(node)).len()
     }
 }
@@ -0,0 +1,64 @@
+use std::fmt;
+use num_bigint::{BigInt, BigUint};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Answer {
+    Int(i64),
+    BigInt(BigInt),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+// Integers that do not fit in an i64 are promoted to a BigInt so that the same answer always ends
+// up in the same variant
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n)
+                        .map(Answer::Int)
+                        .unwrap_or_else(|_| Answer::BigInt(BigInt::from(n)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        i64::try_from(&n)
+            .map(Answer::Int)
+            .unwrap_or(Answer::BigInt(n))
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        BigInt::from(n).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Hash based collections iterate in a different order on every run. Fields holding them write
+// their elements sorted instead, so that two dumps of the same state diff cleanly.
+
+// For `#[serde(serialize_with = "...")]` on a `HashSet`, which deserializes as usual
+pub fn sorted<T, S>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + Ord,
+    S: Serializer,
+{
+    let mut elements: Vec<&T> = set.iter().collect();
+    elements.sort_unstable();
+
+    serializer.collect_seq(elements)
+}
+
+// For `#[serde(with = "...")]` on a `HashMap` whose keys are not strings or integers, which most
+// formats cannot use as map keys. The map is written as a list of sorted `[key, value]` pairs.
+pub mod pairs {
+    use super::*;
+    use std::hash::Hash;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize + Ord,
+        V: Serialize,
+        S: Serializer,
+    {
+        let mut pairs: Vec<(&K, &V)> = map.iter().collect();
+        pairs.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        serializer.collect_seq(pairs)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<(K, V)>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct State {
+        #[serde(serialize_with = "sorted")]
+        seen: HashSet<u32>,
+        #[serde(with = "pairs")]
+        costs: HashMap<Position, u32>,
+    }
+
+    #[test]
+    fn writes_hash_collections_sorted_and_reads_them_back() {
+        let state = State {
+            seen: HashSet::from([3, 1, 2]),
+            costs: HashMap::from([(Position::new(1, 0), 7), (Position::new(0, 4), 5)]),
+        };
+
+        let json = serde_json::to_string(&state).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"seen":[1,2,3],"costs":[[{"row":0,"col":4},5],[{"row":1,"col":0},7]]}"#
+        );
+        assert_eq!(serde_json::from_str::<State>(&json).unwrap(), state);
+    }
+}
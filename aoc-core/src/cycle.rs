@@ -0,0 +1,185 @@
+use std::{collections::{hash_map::Entry, HashMap}, hash::Hash};
+
+// Finding where a process stepping from state to state starts going round in circles, so that the
+// state after any number of steps can be told from the first few. States are compared through a
+// fingerprint of them, which can leave out what does not matter to the cycle: pass `S::clone` to
+// compare them whole.
+
+// The states from step `prefix` on come back every `period` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // The first step whose state is the one of step `n`
+    pub fn step_at(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+// Remembers the step of every fingerprint. `step` is called once for each step in order, so that it
+// can note what happens on the way.
+pub fn hash_map<S, K: Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S, mut fingerprint: impl FnMut(&S) -> K) -> Cycle {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut state = start;
+
+    for n in 0.. {
+        match seen.entry(fingerprint(&state)) {
+            Entry::Occupied(first) => return Cycle { prefix: *first.get(), period: n - first.get() },
+            Entry::Vacant(first) => first.insert(n),
+        };
+        state = step(&state);
+    }
+
+    unreachable!("a cycle is found before the steps run out")
+}
+
+// Keeps two states only, one stepping twice as fast as the other. It takes about three times the
+// steps of `hash_map`.
+pub fn floyd<S: Clone, K: PartialEq>(start: S, mut step: impl FnMut(&S) -> S, mut fingerprint: impl FnMut(&S) -> K) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    // The hare is as many steps ahead as a multiple of the period, they meet again at the prefix
+    let mut prefix = 0;
+    tortoise = start;
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Keeps two states only, moving the slow one to the fast one at every power of two. It takes fewer
+// steps than `floyd`.
+pub fn brent<S: Clone, K: PartialEq>(start: S, mut step: impl FnMut(&S) -> S, mut fingerprint: impl FnMut(&S) -> K) -> Cycle {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, they meet at the prefix
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = (0..period).fold(start, |state, _| step(&state));
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// The state after `n` steps, taking at most the steps of the prefix and one period
+pub fn state_at<S>(start: S, mut step: impl FnMut(&S) -> S, cycle: &Cycle, n: usize) -> S {
+    (0..cycle.step_at(n)).fold(start, |state, _| step(&state))
+}
+
+// Every state up to the cycle coming back, along with it
+#[derive(Debug, Clone)]
+pub struct Run<S> {
+    pub cycle: Cycle,
+    pub states: Vec<S>,
+}
+
+impl<S> Run<S> {
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.step_at(n)]
+    }
+}
+
+// Like `hash_map` comparing the states whole, and keeping them
+pub fn run<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Run<S> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = start;
+
+    let cycle = loop {
+        if let Some(&prefix) = seen.get(&state) {
+            break Cycle { prefix, period: seen.len() - prefix };
+        }
+        let next = step(&state);
+        seen.insert(state, seen.len());
+        state = next;
+    };
+
+    let mut states: Vec<(S, usize)> = seen.into_iter().collect();
+    states.sort_unstable_by_key(|(_, n)| *n);
+
+    Run { cycle, states: states.into_iter().map(|(state, _)| state).collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 → 1 → 2 → 3 → 4 → 5 → 6 → 3
+    fn next(n: &u32) -> u32 {
+        if *n == 6 { 3 } else { n + 1 }
+    }
+
+    #[test]
+    fn every_variant_finds_the_same_cycle() {
+        let expected = Cycle { prefix: 3, period: 4 };
+
+        assert_eq!(hash_map(0, next, |n| *n), expected);
+        assert_eq!(floyd(0, next, |n| *n), expected);
+        assert_eq!(brent(0, next, |n| *n), expected);
+        assert_eq!(run(0, next).cycle, expected);
+
+        // Starting on the cycle
+        assert_eq!(brent(4, next, |n| *n), Cycle { prefix: 0, period: 4 });
+        assert_eq!(floyd(7, |n| *n, |n| *n), Cycle { prefix: 0, period: 1 });
+    }
+
+    #[test]
+    fn tells_the_state_at_any_step() {
+        let cycle = Cycle { prefix: 3, period: 4 };
+        assert_eq!([0, 2, 3, 6, 7, 1_000_000_000].map(|n| cycle.step_at(n)), [0, 2, 3, 6, 3, 4]);
+        assert_eq!(state_at(0, next, &cycle, 1_000_000_001), 5);
+
+        let run = run(0, next);
+        assert_eq!(run.states, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*run.state_at(1_000_000_002), 6);
+    }
+
+    #[test]
+    fn compares_states_by_fingerprint() {
+        // The counter goes up forever, only its parity cycles
+        let cycle = hash_map((0_u64, false), |(count, odd)| (count + 1, !odd), |(_, odd)| *odd);
+        assert_eq!(cycle, Cycle { prefix: 0, period: 2 });
+
+        let mut steps = vec![];
+        hash_map(0, |n| { steps.push(*n); next(n) }, |n| *n);
+        assert_eq!(steps, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,21 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Left, Self::Down, Self::Right];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Left => Self::Right,
+            Self::Down => Self::Up,
+            Self::Right => Self::Left,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use rand::Rng;
+use crate::{Answer, Grid, Position};
+
+// Generated
+// -----------------------------------------------------------------------------
+
+// A random puzzle input, with the answers the generator knows by construction (most only know that
+// the input is valid)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self { input, answers: [None, None] }
+    }
+
+    pub fn with_answer(mut self, part: u8, answer: impl Into<Answer>) -> Self {
+        self.answers[usize::from(part) - 1] = Some(answer.into());
+        self
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        self.answers.get(usize::from(part).checked_sub(1)?)?.as_ref()
+    }
+}
+
+// Names
+// -----------------------------------------------------------------------------
+
+// `count` distinct names of `len` lowercase letters that `keep` accepts, there must be plenty more
+// of them than `count`
+pub fn names<R, F>(rng: &mut R, count: usize, len: usize, keep: F) -> Vec<String>
+where
+    R: Rng,
+    F: Fn(&str) -> bool,
+{
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut names: Vec<String> = vec![];
+
+    while names.len() < count {
+        let name: String = (0..len).map(|_| rng.gen_range(b'a'..=b'z') as char).collect();
+        if keep(&name) && seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+// Shapes
+// -----------------------------------------------------------------------------
+const RING: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+// A random shape of up to `cells` cells of a `side` × `side` grid grown from its middle, cells are
+// only added while the shape keeps no hole and no two of its cells touch only by a corner so that
+// its `boundary` is a single loop
+pub fn polyomino<R: Rng>(rng: &mut R, side: usize, cells: usize) -> Grid<bool> {
+    let mut shape = Grid::new(side.max(1), side.max(1), false);
+    let middle = Position::new(shape.rows() / 2, shape.cols() / 2);
+    shape[middle] = true;
+
+    let mut frontier: Vec<Position> = shape.neighbours4(middle).collect();
+    let mut size: usize = 1;
+    let mut attempts: usize = 0;
+
+    while size < cells && !frontier.is_empty() && attempts < 50 * cells {
+        attempts += 1;
+
+        let cell = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if shape[cell] || !can_grow(&shape, cell) {
+            continue;
+        }
+
+        shape[cell] = true;
+        size += 1;
+        frontier.extend(shape.neighbours4(cell).filter(|&neighbour| !shape[neighbour]));
+    }
+
+    shape
+}
+
+// The cells of the shape around `cell` must form a single run, and none of them may touch it only
+// by a corner
+fn can_grow(shape: &Grid<bool>, cell: Position) -> bool {
+    let inside = |(dr, dc): (isize, isize)| {
+        let row = cell.row.checked_add_signed(dr);
+        let col = cell.col.checked_add_signed(dc);
+
+        matches!((row, col), (Some(row), Some(col)) if shape.get(Position::new(row, col)) == Some(&true))
+    };
+
+    let runs = (0..RING.len())
+        .filter(|&i| inside(RING[i]) && !inside(RING[(i + RING.len() - 1) % RING.len()]))
+        .count();
+    let pinched = RING
+        .iter()
+        .filter(|(dr, dc)| *dr != 0 && *dc != 0)
+        .any(|&(dr, dc)| inside((dr, dc)) && !inside((dr, 0)) && !inside((0, dc)));
+
+    runs == 1 && !pinched
+}
+
+// The corners of the cells walked around the outside of a `polyomino`, one unit step apart, the
+// corner `(row, col)` being the top left one of the cell `(row, col)`
+pub fn boundary(shape: &Grid<bool>) -> Vec<Position> {
+    let filled = |row: usize, col: usize, dr: usize, dc: usize| {
+        match (row.checked_sub(dr), col.checked_sub(dc)) {
+            (Some(row), Some(col)) => shape.get(Position::new(row, col)) == Some(&true),
+            _ => false,
+        }
+    };
+
+    // Each corner and the corners one step right or down along an edge between a filled cell and
+    // an empty one
+    let edges = |corner: Position| {
+        let (row, col) = (corner.row, corner.col);
+        let right = filled(row, col, 1, 0) != filled(row, col, 0, 0);
+        let down = filled(row, col, 0, 1) != filled(row, col, 0, 0);
+        let left = col > 0 && filled(row, col - 1, 1, 0) != filled(row, col - 1, 0, 0);
+        let up = row > 0 && filled(row - 1, col, 0, 1) != filled(row - 1, col, 0, 0);
+
+        [
+            right.then(|| Position::new(row, col + 1)),
+            down.then(|| Position::new(row + 1, col)),
+            left.then(|| Position::new(row, col.wrapping_sub(1))),
+            up.then(|| Position::new(row.wrapping_sub(1), col)),
+        ]
+    };
+
+    let Some(start) = shape.find(|&filled| filled) else {
+        return vec![];
+    };
+
+    let mut corners: Vec<Position> = vec![start];
+    let mut previous = start;
+    let mut current = Position::new(start.row, start.col + 1);
+
+    while current != start {
+        corners.push(current);
+
+        let next = edges(current)
+            .into_iter()
+            .flatten()
+            .find(|&next| next != previous)
+            .expect("the boundary of a polyomino is a loop");
+
+        previous = current;
+        current = next;
+    }
+
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use super::*;
+
+    #[test]
+    fn walks_every_edge_of_the_shape_once() {
+        for seed in 0..20 {
+            let shape = polyomino(&mut StdRng::seed_from_u64(seed), 12, 60);
+            let corners = boundary(&shape);
+
+            // Every filled cell adds its sides next to empty cells (or the border)
+            let perimeter: usize = shape
+                .iter()
+                .filter(|(_, &filled)| filled)
+                .map(|(cell, _)| 4 - shape.neighbours4(cell).filter(|&neighbour| shape[neighbour]).count())
+                .sum();
+
+            assert_eq!(corners.len(), perimeter);
+            assert_eq!(corners.iter().collect::<HashSet<_>>().len(), corners.len());
+
+            for (a, b) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+                assert_eq!(a.row.abs_diff(b.row) + a.col.abs_diff(b.col), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn draws_distinct_names() {
+        let names = names(&mut StdRng::seed_from_u64(1), 200, 2, |name| name != "in");
+
+        assert_eq!(names.len(), 200);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 200);
+        assert!(names.iter().all(|name| name.len() == 2 && name != "in"));
+    }
+
+    #[test]
+    fn records_the_known_answers() {
+        let generated = Generated::new("1\n".to_string()).with_answer(2, 7);
+
+        assert_eq!(generated.answer(1), None);
+        assert_eq!(generated.answer(2), Some(&Answer::Int(7)));
+        assert_eq!(generated.answer(3), None);
+    }
+}
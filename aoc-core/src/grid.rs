@@ -0,0 +1,364 @@
+use std::{fmt, ops::{Index, IndexMut}, str::FromStr};
+use crate::{Direction, ParseError};
+
+// Position
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row, col }
+    }
+}
+
+// Grid
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { cells: vec![cell; rows * cols], rows, cols }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Self { rows: rows.len(), cols, cells: rows.into_iter().flatten().collect() })
+    }
+
+    // Every line of `s` becomes a row, `cell` maps each character and gets its errors located
+    pub fn parse_with<F>(s: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        let mut cells: Vec<T> = vec![];
+        let mut rows: usize = 0;
+        let mut cols: Option<usize> = None;
+
+        for line in s.lines() {
+            let mut width: usize = 0;
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|e| e.with_location(&line[i..]))?);
+                width += 1;
+            }
+
+            match cols {
+                Some(cols) if cols != width => {
+                    return Err(ParseError::at(line, format!("expected {} columns, found {}", cols, width)));
+                },
+                _ => cols = Some(width),
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self { cells, rows, cols }),
+            _ => Err(ParseError::at(s, "expected at least one row")),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.rows && position.col < self.cols
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[position.row * self.cols + position.col])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.cols + position.col])
+        } else {
+            None
+        }
+    }
+
+    // Toroidal indexing, the grid repeats itself infinitely in every direction
+    pub fn get_wrapping(&self, row: isize, col: isize) -> &T {
+        let row = row.rem_euclid(self.rows as isize) as usize;
+        let col = col.rem_euclid(self.cols as isize) as usize;
+
+        &self.cells[row * self.cols + col]
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    // One slice per row, even when they are empty
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn iter_columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let cols = self.cols;
+        (0..self.rows * self.cols).map(move |i| Position::new(i / cols, i % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid { cells: self.cells.iter().map(f).collect(), rows: self.rows, cols: self.cols }
+    }
+
+    // Moves `steps` cells from `position` towards `direction`, `None` when that leaves the grid
+    pub fn step(&self, position: Position, direction: Direction, steps: usize) -> Option<Position> {
+        let next = match direction {
+            Direction::Up => Position::new(position.row.checked_sub(steps)?, position.col),
+            Direction::Left => Position::new(position.row, position.col.checked_sub(steps)?),
+            Direction::Down => Position::new(position.row.checked_add(steps)?, position.col),
+            Direction::Right => Position::new(position.row, position.col.checked_add(steps)?),
+        };
+
+        self.contains(next).then_some(next)
+    }
+
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = (Direction, Position)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step(position, direction, 1)?)))
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position).map(|(_, neighbour)| neighbour)
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dr, dc)| {
+                let row = position.row.checked_add_signed(dr)?;
+                let col = position.col.checked_add_signed(dc)?;
+                let neighbour = Position::new(row, col);
+
+                self.contains(neighbour).then_some(neighbour)
+            })
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.iter_columns().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.iter_columns().flat_map(|column| column.rev()).cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.iter_columns().rev().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| panic!("{:?} out of bounds", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} out of bounds", position))
+    }
+}
+
+// Cells are parsed with their `TryFrom<char>` implementation
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    ParseError: From<T::Error>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| Ok(T::try_from(c)?))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = grid("abc\ndef\n");
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Position::new(1, 0)], 'd');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn reports_ragged_rows_and_bad_cells() {
+        let input = "ab\nabc\n";
+        let error = Grid::<char>::from_str(input).unwrap_err().locate(input);
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let input = "..\n.x\n";
+        let error = Grid::parse_with(input, |c| match c {
+            '.' => Ok(()),
+            c => Err(crate::parse::unexpected(c)),
+        })
+        .unwrap_err()
+        .locate(input);
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected character `x`");
+
+        assert!(Grid::<char>::from_str("").is_err());
+    }
+
+    #[test]
+    fn finds_neighbours_within_bounds() {
+        let grid = grid("abc\ndef\nghi");
+
+        let corner: Vec<char> = grid.neighbours4(Position::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, ['d', 'b']);
+
+        let mut centre: Vec<char> = grid.neighbours8(Position::new(1, 1)).map(|p| grid[p]).collect();
+        centre.sort();
+        assert_eq!(centre, ['a', 'b', 'c', 'd', 'f', 'g', 'h', 'i']);
+
+        assert_eq!(grid.neighbours8(Position::new(2, 2)).count(), 3);
+        assert_eq!(grid.step(Position::new(1, 1), Direction::Right, 1), Some(Position::new(1, 2)));
+        assert_eq!(grid.step(Position::new(1, 1), Direction::Right, 2), None);
+        assert_eq!(grid.step(Position::new(1, 1), Direction::Up, 2), None);
+    }
+
+    #[test]
+    fn wraps_around_the_edges() {
+        let grid = grid("ab\ncd");
+
+        assert_eq!(*grid.get_wrapping(-1, -1), 'd');
+        assert_eq!(*grid.get_wrapping(2, 3), 'b');
+        assert_eq!(*grid.get_wrapping(-4, 0), 'a');
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = grid("abc\ndef");
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn finds_and_maps_cells() {
+        let grid = grid("..\n.S");
+
+        assert_eq!(grid.find(|c| *c == 'S'), Some(Position::new(1, 1)));
+        assert_eq!(grid.map(|c| *c == '.').iter().filter(|(_, open)| **open).count(), 3);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[test]
+    fn walks_empty_grids() {
+        let empty: Grid<char> = Grid::from_rows(vec![]).unwrap();
+
+        assert_eq!(empty.iter_rows().count(), 0);
+        assert_eq!(empty.iter_columns().count(), 0);
+        assert_eq!(empty.positions().count(), 0);
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(empty.transpose(), empty);
+
+        let no_columns: Grid<char> = Grid::new(2, 0, '.');
+
+        assert_eq!(no_columns.iter_rows().collect::<Vec<&[char]>>(), [&[] as &[char], &[]]);
+        assert_eq!(no_columns.iter_columns().count(), 0);
+        assert_eq!(no_columns.to_string(), "\n\n");
+        assert_eq!(no_columns.transpose(), Grid::new(0, 2, '.'));
+    }
+}
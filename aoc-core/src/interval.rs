@@ -0,0 +1,440 @@
+use std::ops::Range;
+
+// Sets and maps of whole numbers kept as ranges, so that what they hold can be worked on without
+// going through the numbers one by one. Ranges are half-open like the ones of the standard library.
+
+// Sets
+// -----------------------------------------------------------------------------
+
+// The ranges are kept sorted, and apart from each other: overlapping or adjacent ranges are merged
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // How many numbers the set holds, which always fits as the ranges do not overlap
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|range| range.end.abs_diff(range.start)).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges.get(index).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        *self = self.ranges().chain([range]).collect();
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.ranges().chain(other.ranges()).collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // The range ending first cannot overlap anything further in the other set
+            if a.end <= b.end { i += 1 } else { j += 1 }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    // The numbers of `self` that are not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip the ranges of `other` ending before this one, the next ones of `self` start later
+            while other.ranges.get(j).is_some_and(|b| b.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(b) = other.ranges.get(k).filter(|b| b.start < range.end) {
+                if start < b.start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    // The numbers below `at`, and the ones from `at` on
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let below = self.ranges().map(|range| range.start..range.end.min(at));
+        let above = self.ranges().map(|range| range.start.max(at)..range.end);
+
+        (below.collect(), above.collect())
+    }
+
+    // Every number moved by `offset`, `None` when one of them would leave the `i64`s
+    pub fn shift(&self, offset: i64) -> Option<IntervalSet> {
+        let ranges = self.ranges
+            .iter()
+            .map(|range| Some(range.start.checked_add(offset)?..range.end.checked_add(offset)?))
+            .collect::<Option<_>>()?;
+
+        Some(IntervalSet { ranges })
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<i64>> = iter.into_iter().filter(|range| !range.is_empty()).collect();
+        sorted.sort_unstable_by_key(|range| range.start);
+
+        let mut ranges: Vec<Range<i64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+// Maps
+// -----------------------------------------------------------------------------
+
+// Moves each range of numbers by an offset of its own, the numbers outside of every piece are left
+// where they are. The pieces are kept sorted and apart from each other, and the numbers of a piece
+// always land on `i64`s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearMap {
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl LinearMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pieces(&self) -> impl Iterator<Item = (Range<i64>, i64)> + '_ {
+        self.pieces.iter().cloned()
+    }
+
+    // The numbers covered by a piece
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces().map(|(range, _)| range).collect()
+    }
+
+    // Where the piece overlaps earlier ones, it replaces them. Panics when the numbers of the range
+    // moved by `offset` would not fit in an `i64`, which `fits` tells beforehand.
+    pub fn insert(&mut self, range: Range<i64>, offset: i64) {
+        assert!(Self::fits(&range, offset), "{:?} moved by {} leaves the i64s", range, offset);
+        if range.is_empty() {
+            return;
+        }
+
+        let covered = IntervalSet::from(range.clone());
+        let mut pieces: Vec<(Range<i64>, i64)> = self.pieces
+            .iter()
+            .flat_map(|(piece, offset)| {
+                let left = IntervalSet::from(piece.clone()).difference(&covered);
+                left.ranges.into_iter().map(move |left| (left, *offset))
+            })
+            .collect();
+        pieces.push((range, offset));
+        pieces.sort_unstable_by_key(|(piece, _)| piece.start);
+
+        self.pieces = pieces;
+    }
+
+    // Whether the range moved by `offset` is still a range of `i64`s
+    pub fn fits(range: &Range<i64>, offset: i64) -> bool {
+        range.is_empty() || (range.start.checked_add(offset).is_some() && range.end.checked_add(offset).is_some())
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(range, _)| range.end <= value);
+
+        match self.pieces.get(index) {
+            Some((range, offset)) if range.start <= value => value + offset,
+            _ => value,
+        }
+    }
+
+    // Where the numbers of `set` end up
+    pub fn image(&self, set: &IntervalSet) -> IntervalSet {
+        let mut image = set.difference(&self.domain());
+
+        for (range, offset) in self.pieces() {
+            let moved = set
+                .intersection(&range.into())
+                .shift(offset)
+                .expect("the numbers of a piece land on i64s");
+            image = image.union(&moved);
+        }
+
+        image
+    }
+
+    // The map taking a number through `self` then `next`, with the pieces left out where the two
+    // offsets cancel out. `None` when the offsets add up past the `i64`s.
+    pub fn then(&self, next: &LinearMap) -> Option<LinearMap> {
+        let next_domain = next.domain();
+        let mut pieces: Vec<(Range<i64>, i64)> = vec![];
+
+        for (range, offset) in self.pieces() {
+            let moved = IntervalSet::from(range).shift(offset)?;
+            let back = offset.checked_neg()?;
+
+            for (next_range, next_offset) in next.pieces() {
+                let overlap = moved.intersection(&next_range.into()).shift(back)?;
+                let total = offset.checked_add(next_offset)?;
+                pieces.extend(overlap.ranges.into_iter().map(|range| (range, total)));
+            }
+            let untouched = moved.difference(&next_domain).shift(back)?;
+            pieces.extend(untouched.ranges.into_iter().map(|range| (range, offset)));
+        }
+
+        // The numbers `self` leaves alone only go through `next`
+        let own_domain = self.domain();
+        for (next_range, next_offset) in next.pieces() {
+            let untouched = IntervalSet::from(next_range).difference(&own_domain);
+            pieces.extend(untouched.ranges.into_iter().map(|range| (range, next_offset)));
+        }
+
+        pieces.retain(|(_, offset)| *offset != 0);
+        pieces.sort_unstable_by_key(|(range, _)| range.start);
+
+        Some(LinearMap { pieces })
+    }
+}
+
+// Boxes
+// -----------------------------------------------------------------------------
+
+// The numbers within a range on each of `N` axes. A box with an empty side holds nothing, the
+// operations making boxes give `None` rather than one of those.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cuboid<const N: usize> {
+    sides: [Range<i64>; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(sides: [Range<i64>; N]) -> Self {
+        Self { sides }
+    }
+
+    pub fn side(&self, axis: usize) -> Range<i64> {
+        self.sides[axis].clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sides.iter().any(Range::is_empty)
+    }
+
+    // How many points the box holds, `None` when it does not fit
+    pub fn volume(&self) -> Option<u64> {
+        if self.is_empty() {
+            return Some(0);
+        }
+
+        self.sides.iter().try_fold(1_u64, |volume, side| volume.checked_mul(side.end.abs_diff(side.start)))
+    }
+
+    // The part of the box whose side on `axis` is within `range`
+    pub fn restrict(&self, axis: usize, range: Range<i64>) -> Option<Self> {
+        let mut sides = self.sides.clone();
+        let side = &sides[axis];
+        sides[axis] = side.start.max(range.start)..side.end.min(range.end);
+
+        Some(Self { sides }).filter(|restricted| !restricted.is_empty())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        (0..N).try_fold(self.clone(), |cuboid, axis| cuboid.restrict(axis, other.side(axis)))
+    }
+
+    // The part of the box below `at` on `axis`, and the part from `at` on
+    pub fn split_at(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        (self.restrict(axis, i64::MIN..at), self.restrict(axis, at..i64::MAX))
+    }
+
+    // The points of `self` outside of `other`, as at most two boxes per axis not overlapping each
+    // other
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersection(other).is_none() {
+            return if self.is_empty() { vec![] } else { vec![self.clone()] };
+        }
+
+        let mut pieces = vec![];
+        let mut rest = self.clone();
+        for axis in 0..N {
+            let side = other.side(axis);
+            let (below, within) = rest.split_at(axis, side.start);
+            let (within, above) = within.expect("the boxes overlap").split_at(axis, side.end);
+
+            pieces.extend(below.into_iter().chain(above));
+            rest = within.expect("the boxes overlap");
+        }
+
+        pieces
+    }
+}
+
+// The sides are written as a list, `serde` only handles arrays of a given length
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Cuboid<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.sides)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Cuboid<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sides: Vec<Range<i64>> = serde::Deserialize::deserialize(deserializer)?;
+        let length = sides.len();
+
+        let sides = sides
+            .try_into()
+            .map_err(|_| serde::de::Error::invalid_length(length, &format!("{} sides", N).as_str()))?;
+
+        Ok(Self { sides })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set<const N: usize>(ranges: [Range<i64>; N]) -> IntervalSet {
+        ranges.into_iter().collect()
+    }
+
+    #[test]
+    fn merges_ranges_into_sets() {
+        let merged = set([5..8, 0..2, 1..3, 8..9, 4..4]);
+
+        assert_eq!(merged.ranges().collect::<Vec<_>>(), vec![0..3, 5..9]);
+        assert_eq!(merged.len(), 7);
+        assert_eq!((merged.min(), merged.max()), (Some(0), Some(8)));
+        assert!(merged.contains(2) && merged.contains(5) && !merged.contains(3) && !merged.contains(9));
+        assert!(IntervalSet::from(3..3).is_empty());
+        assert_eq!(set([i64::MIN..0, 0..i64::MAX]).len(), u64::MAX);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set([0..10, 20..30]);
+        let b = set([5..25, 28..40]);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b), set([5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set([0..5, 25..28]));
+        assert_eq!(b.difference(&a), set([10..20, 30..40]));
+        assert_eq!(a.difference(&set([0..2, 4..6, 8..22])), set([2..4, 6..8, 22..30]));
+        assert_eq!(a.split_at(25), (set([0..10, 20..25]), IntervalSet::from(25..30)));
+        assert_eq!(a.shift(-5), Some(set([-5..5, 15..25])));
+        assert_eq!(a.shift(i64::MAX), None);
+    }
+
+    #[test]
+    fn maps_ranges_piecewise() {
+        // The seed-to-soil map of day 5
+        let mut map = LinearMap::new();
+        map.insert(98..100, -48);
+        map.insert(50..98, 2);
+
+        assert_eq!([0, 49, 50, 97, 98, 99, 100].map(|value| map.get(value)), [0, 49, 52, 99, 50, 51, 100]);
+        assert_eq!(map.image(&set([79..93, 55..68])), set([57..70, 81..95]));
+        assert_eq!(map.image(&IntervalSet::from(90..110)), set([50..52, 92..110]));
+
+        // Later pieces replace the ones they overlap
+        map.insert(40..60, 1);
+        assert_eq!(map.pieces().collect::<Vec<_>>(), vec![(40..60, 1), (60..98, 2), (98..100, -48)]);
+        assert!(!LinearMap::fits(&(0..10), i64::MAX - 5));
+    }
+
+    #[test]
+    fn composes_maps() {
+        let mut first = LinearMap::new();
+        first.insert(0..10, 10);
+        let mut second = LinearMap::new();
+        second.insert(15..30, -15);
+
+        let both = first.then(&second).unwrap();
+
+        for value in -5..40 {
+            assert_eq!(both.get(value), second.get(first.get(value)), "{}", value);
+        }
+        assert_eq!(both.pieces().collect::<Vec<_>>(), vec![(0..5, 10), (5..10, -5), (15..30, -15)]);
+
+        let mut far = LinearMap::new();
+        far.insert(i64::MIN..i64::MIN + 1, i64::MAX);
+        let mut further = LinearMap::new();
+        further.insert(-1..0, i64::MAX);
+        assert_eq!(far.then(&further), None);
+    }
+
+    #[test]
+    fn cuts_boxes() {
+        let cuboid = Cuboid::new([0..4, 0..4]);
+        let hole = Cuboid::new([1..3, 2..6]);
+
+        assert_eq!(cuboid.volume(), Some(16));
+        assert_eq!(cuboid.intersection(&hole), Some(Cuboid::new([1..3, 2..4])));
+        assert_eq!(cuboid.intersection(&Cuboid::new([4..5, 0..4])), None);
+        assert_eq!(cuboid.split_at(0, 1), (Some(Cuboid::new([0..1, 0..4])), Some(Cuboid::new([1..4, 0..4]))));
+        assert_eq!(cuboid.split_at(1, 0), (None, Some(cuboid.clone())));
+
+        let rest = cuboid.difference(&hole);
+        assert_eq!(rest.iter().map(|piece| piece.volume().unwrap()).sum::<u64>(), 12);
+        for (i, a) in rest.iter().enumerate() {
+            assert_eq!(a.intersection(&hole), None);
+            assert!(rest[i + 1..].iter().all(|b| a.intersection(b).is_none()));
+        }
+        assert_eq!(cuboid.difference(&Cuboid::new([5..6, 0..1])), vec![cuboid.clone()]);
+        assert_eq!(cuboid.difference(&cuboid), vec![]);
+        assert_eq!(Cuboid::new([0..4000, 0..4000, 0..4000, 0..4000]).volume(), Some(4000_u64.pow(4)));
+    }
+}
@@ -0,0 +1,24 @@
+pub mod answer;
+#[cfg(feature = "serde")]
+pub mod canonical;
+pub mod cycle;
+pub mod direction;
+pub mod generate;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod params;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod visual;
+
+pub use answer::Answer;
+pub use direction::Direction;
+pub use generate::Generated;
+pub use grid::{Grid, Position};
+pub use params::{Parameters, Params};
+pub use parse::ParseError;
+pub use solution::{IntoAnswer, Solution, Stages, Timings, Tuned};
+pub use visual::Frame;
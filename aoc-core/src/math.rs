@@ -0,0 +1,417 @@
+use std::{cmp::Ordering, fmt, ops::RangeInclusive};
+use num_bigint::BigUint;
+
+// Exact integer maths for the puzzles. What could overflow is checked and comes back as `None`
+// rather than wrapping around, and nothing goes through floats.
+
+// Number theory
+// -----------------------------------------------------------------------------
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a.into(), b.into()) as u64
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Of no numbers at all is 1
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+// Same as `lcm_all` for when the multiple may not fit in a u64
+pub fn big_lcm_all(numbers: impl IntoIterator<Item = u64>) -> BigUint {
+    numbers.into_iter().fold(BigUint::from(1_u8), |multiple, number| {
+        if number == 0 {
+            return BigUint::ZERO;
+        }
+        let (mut a, mut b) = (multiple.clone(), BigUint::from(number));
+        while b != BigUint::ZERO {
+            (a, b) = (b.clone(), a % b);
+        }
+
+        multiple / a * number
+    })
+}
+
+// `(g, x, y)` with `g = gcd(a, b)`, never negative, and `a x + b y = g`
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+// Chinese remainder theorem: the `x` with `x ≡ r (mod m)` for every `(r, m)`, as `(x, lcm of the
+// moduli)` with `0 <= x < lcm`. The moduli need not be coprime. `None` when the congruences
+// contradict each other, a modulus is 0 or the lcm does not fit in a u64.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold((0, 1), |(x, m): (u64, u64), (r, n)| {
+        if n == 0 {
+            return None;
+        }
+
+        // x + m k ≡ r (mod n) has a solution when gcd(m, n) divides r - x
+        let (g, inverse, _) = ext_gcd(m.into(), n.into());
+        let difference = i128::from(r) - i128::from(x);
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = i128::from(n) / g;
+        let k = ((difference / g).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128) % step as u128;
+        let modulus = lcm(m, n)?;
+
+        Some(((u128::from(x) + u128::from(m) * k) as u64 % modulus, modulus))
+    })
+}
+
+// Same as `crt` for when the lcm may not fit in a u64
+pub fn big_crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(BigUint, BigUint)> {
+    congruences.into_iter().try_fold((BigUint::ZERO, BigUint::from(1_u8)), |(x, m), (r, n)| {
+        if n == 0 {
+            return None;
+        }
+
+        // Same steps as `crt` modulo n, where the numbers fit in a u64 again
+        let reduced = |big: &BigUint| u64::try_from(big % n).expect("the remainder is below n");
+        let (g, inverse, _) = ext_gcd(reduced(&m).into(), n.into());
+        let difference = i128::from(r) - i128::from(reduced(&x));
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = i128::from(n) / g;
+        let k = ((difference / g).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128) % step as u128;
+        let modulus = &m * step as u64;
+
+        Some(((x + m * k as u64) % &modulus, modulus))
+    })
+}
+
+// Quadratics
+// -----------------------------------------------------------------------------
+
+// The integers `x` for which `a x² + b x + c < 0`, with `a > 0`: those strictly between the two
+// roots, an empty range when there are none. `None` when the coefficients are too large to work
+// with exactly.
+pub fn below_zero(a: i64, b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    assert!(a > 0, "the parabola must open upwards");
+
+    let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+    let value = |x: i128| a.checked_mul(x)?.checked_add(b)?.checked_mul(x)?.checked_add(c);
+
+    let delta = (b * b).checked_sub(a.checked_mul(c)?.checked_mul(4)?)?;
+    if delta <= 0 {
+        return Some(RangeInclusive::new(1, 0));
+    }
+
+    // The integer square root is at most 1 below the real one, which puts these estimates at most
+    // 1 away from the first and last integers between the roots
+    let root = delta.isqrt();
+    let low = (-b - root).div_euclid(2 * a);
+    let low = if value(low)? < 0 { low } else { low + 1 };
+    let high = -(b - root).div_euclid(2 * a);
+    let high = if value(high)? < 0 { high } else { high - 1 };
+
+    Some(i64::try_from(low).ok()?..=i64::try_from(high).ok()?)
+}
+
+// Polygons
+// -----------------------------------------------------------------------------
+
+// Twice the area enclosed by the vertices, given in order around the polygon (shoelace formula),
+// which is a whole number for integer vertices
+pub fn shoelace(vertices: &[(i64, i64)]) -> Option<u64> {
+    let twice = edges(vertices).try_fold(0_i128, |twice, ((x1, y1), (x2, y2))| {
+        let cross = i128::from(x1).checked_mul(y2.into())?.checked_sub(i128::from(x2).checked_mul(y1.into())?)?;
+        twice.checked_add(cross)
+    })?;
+
+    u64::try_from(twice.unsigned_abs()).ok()
+}
+
+// The points of the grid on the edges of the polygon, an edge from `a` to `b` holds
+// `gcd(|dx|, |dy|)` of them not counting `a`
+pub fn boundary_points(vertices: &[(i64, i64)]) -> Option<u64> {
+    edges(vertices).try_fold(0_u64, |points, ((x1, y1), (x2, y2))| points.checked_add(gcd(x1.abs_diff(x2), y1.abs_diff(y2))))
+}
+
+// Pick's theorem, `A = i + b / 2 - 1`: the points of the grid strictly inside a polygon with
+// integer vertices, from twice its area and the points on its edges
+pub fn interior_points(twice_area: u64, boundary: u64) -> Option<u64> {
+    Some(twice_area.checked_add(2)?.checked_sub(boundary)? / 2)
+}
+
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    vertices.iter().copied().zip(vertices.iter().copied().cycle().skip(1))
+}
+
+// Rationals
+// -----------------------------------------------------------------------------
+
+// An exact fraction, kept reduced with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+impl Ratio {
+    // `None` for a zero denominator
+    pub fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let g = gcd_u128(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / g, den / g);
+
+        if den < 0 {
+            Some(Self { num: num.checked_neg()?, den: den.checked_neg()? })
+        } else {
+            Some(Self { num, den })
+        }
+    }
+
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.num < 0
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let num = self.num.checked_mul(other.den)?.checked_add(other.num.checked_mul(self.den)?)?;
+
+        Self::new(num, self.den.checked_mul(other.den)?)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::new(self.num.checked_mul(other.num)?, self.den.checked_mul(other.den)?)
+    }
+}
+
+impl From<i64> for Ratio {
+    fn from(n: i64) -> Self {
+        Self { num: n.into(), den: 1 }
+    }
+}
+
+// Compares the whole parts and then the inverses of what is left, like continued fractions do, so
+// that nothing gets multiplied
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn compare(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+            let (whole_a, rest_a) = (a.div_euclid(b), a.rem_euclid(b));
+            let (whole_c, rest_c) = (c.div_euclid(d), c.rem_euclid(d));
+
+            whole_a.cmp(&whole_c).then_with(|| match (rest_a, rest_c) {
+                (0, 0) => Ordering::Equal,
+                (0, _) => Ordering::Less,
+                (_, 0) => Ordering::Greater,
+                _ => compare(d, rest_c, b, rest_a),
+            })
+        }
+
+        compare(self.num, self.den, other.num, other.den)
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{}", self.num, den),
+        }
+    }
+}
+
+// Lines
+// -----------------------------------------------------------------------------
+
+// Where the lines `p + t v` and `q + s w` cross
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Intersection {
+    pub t: Ratio,
+    pub s: Ratio,
+    pub x: Ratio,
+    pub y: Ratio,
+}
+
+// `None` when the lines are parallel, or too far apart to work with exactly
+pub fn intersect(p: (i64, i64), v: (i64, i64), q: (i64, i64), w: (i64, i64)) -> Option<Intersection> {
+    let [vx, vy, wx, wy] = [v.0, v.1, w.0, w.1].map(i128::from);
+    let (dx, dy) = (i128::from(q.0) - i128::from(p.0), i128::from(q.1) - i128::from(p.1));
+
+    // Cramer's rule on t v - s w = q - p
+    let det = (wx * vy).checked_sub(vx * wy)?;
+    let t = Ratio::new(wx.checked_mul(dy)?.checked_sub(wy.checked_mul(dx)?)?, det)?;
+    let s = Ratio::new(vx.checked_mul(dy)?.checked_sub(vy.checked_mul(dx)?)?, det)?;
+
+    let x = Ratio::from(p.0).checked_add(t.checked_mul(v.0.into())?)?;
+    let y = Ratio::from(p.1).checked_add(t.checked_mul(v.1.into())?)?;
+
+    Some(Intersection { t, s, x, y })
+}
+
+// Polynomials
+// -----------------------------------------------------------------------------
+
+// The value at `x` of the polynomial of lowest degree going through `(i, values[i])`, which is what
+// extending the rows of differences of the values gives (Newton's forward differences)
+pub fn extrapolate(values: &[i64], x: i64) -> Option<i64> {
+    let mut differences: Vec<i128> = values.iter().copied().map(i128::from).collect();
+    let x = i128::from(x);
+    // The binomial coefficient of `x` over `k`, which is a whole number for negative `x` too
+    let mut binomial: i128 = 1;
+    let mut value: i128 = 0;
+
+    for k in 0..values.len() as i128 {
+        if k > 0 {
+            differences = differences.windows(2).map(|pair| pair[1].checked_sub(pair[0])).collect::<Option<_>>()?;
+            binomial = binomial.checked_mul(x - (k - 1))? / k;
+        }
+        value = value.checked_add(differences[0].checked_mul(binomial)?)?;
+    }
+
+    i64::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_common_divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(big_lcm_all([4, 6]), BigUint::from(12_u8));
+        assert_eq!(big_lcm_all([u64::MAX, u64::MAX - 1]), BigUint::from(u64::MAX) * (u64::MAX - 1));
+        assert_eq!(big_lcm_all([3, 0]), BigUint::ZERO);
+
+        let (g, x, y) = ext_gcd(240, -46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x - 46 * y, 2);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing a factor
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(-1, 4)]), Some((3, 4)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 0)]), None);
+
+        assert_eq!(big_crt([(2, 3), (3, 5), (2, 7)]), Some((BigUint::from(23_u8), BigUint::from(105_u8))));
+        assert_eq!(big_crt([(3, 4), (5, 6), (-1, 4)]), Some((BigUint::from(11_u8), BigUint::from(12_u8))));
+        assert_eq!(big_crt([(1, 4), (2, 6)]), None);
+        // The lcm outgrows a u64
+        let (a, b) = (u64::MAX, u64::MAX - 1);
+        assert_eq!(big_crt([(0, a), (1, b)]), Some((BigUint::from(a), BigUint::from(a) * b)));
+        assert_eq!(big_crt([(1, a), (0, b)]), Some((BigUint::from(b) * b, BigUint::from(a) * b)));
+    }
+
+    #[test]
+    fn bounds_quadratics_exactly() {
+        // The races of day 6, the charging times beating the record
+        assert_eq!(below_zero(1, -7, 9), Some(2..=5));
+        assert_eq!(below_zero(1, -30, 200), Some(11..=19));
+        // Roots on whole numbers are excluded
+        assert_eq!(below_zero(1, -7, 10), Some(3..=4));
+        assert!(below_zero(1, -2, 1).unwrap().is_empty());
+        assert!(below_zero(4, -10, 6).unwrap().is_empty());
+        assert_eq!(below_zero(2, 0, -9), Some(-2..=2));
+        assert_eq!(below_zero(1, -71530, 940200), Some(14..=71516));
+        // Past the 53 bits of precision of a float
+        assert_eq!(below_zero(1, -(1 << 60), (1 << 60) - 1), Some(2..=(1 << 60) - 2));
+        assert_eq!(below_zero(i64::MAX, 0, i64::MAX), None);
+    }
+
+    #[test]
+    fn measures_polygons() {
+        let square = [(0, 0), (0, 2), (2, 2), (2, 0)];
+        assert_eq!(shoelace(&square), Some(8));
+        assert_eq!(boundary_points(&square), Some(8));
+        assert_eq!(interior_points(8, 8), Some(1));
+
+        let triangle = [(0, 0), (4, 0), (0, 3)];
+        assert_eq!(shoelace(&triangle), Some(12));
+        assert_eq!(boundary_points(&triangle), Some(8));
+        assert_eq!(interior_points(12, 8), Some(3));
+    }
+
+    #[test]
+    fn orders_ratios_exactly() {
+        let third = Ratio::new(1, 3).unwrap();
+
+        assert_eq!(Ratio::new(2, -6), Some(Ratio::new(-1, 3).unwrap()));
+        assert_eq!(Ratio::new(1, 0), None);
+        assert!(third < Ratio::new(1, 2).unwrap());
+        assert!(Ratio::new(-1, 3).unwrap() < Ratio::from(0));
+        // Multiplying these out would overflow
+        assert!(Ratio::new(i128::MAX - 2, i128::MAX - 1).unwrap() < Ratio::new(i128::MAX - 1, i128::MAX).unwrap());
+        assert_eq!(third.checked_add(third).unwrap().checked_mul(Ratio::from(3)).unwrap().to_integer(), Some(2));
+        assert_eq!(third.to_string(), "1/3");
+    }
+
+    #[test]
+    fn intersects_lines_exactly() {
+        // The first two hailstones of day 24
+        let crossing = intersect((19, 13), (-2, 1), (18, 19), (-1, -1)).unwrap();
+        assert_eq!((crossing.x, crossing.y), (Ratio::new(43, 3).unwrap(), Ratio::new(46, 3).unwrap()));
+        assert!(!crossing.t.is_negative() && !crossing.s.is_negative());
+
+        assert_eq!(intersect((18, 19), (-1, -1), (20, 25), (-2, -2)), None);
+    }
+
+    #[test]
+    fn extrapolates_polynomials() {
+        let history = [10, 13, 16, 21, 30, 45];
+
+        assert_eq!(extrapolate(&history, 6), Some(68));
+        assert_eq!(extrapolate(&history, -1), Some(5));
+        assert_eq!(extrapolate(&[3, 3, 3], 100), Some(3));
+        assert_eq!(extrapolate(&[], 4), Some(0));
+        assert_eq!(extrapolate(&[0, i64::MAX], 2), None);
+    }
+}
@@ -0,0 +1,154 @@
+use std::{collections::{HashMap, VecDeque}, hash::Hash};
+
+// A cache for the results of a recursive function, under a key the caller picks: the smaller the
+// key, the cheaper the lookups. The function gets the cache back to make its recursive calls with.
+//
+//     fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//         if n < 2 {
+//             return n;
+//         }
+//         memo.get_or_insert_with(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+//     }
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    // The keys in the order they came in, only kept for a bounded cache
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: Stats,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    // Values dropped to keep a bounded cache under its capacity
+    pub evictions: u64,
+}
+
+impl Stats {
+    // The share of the lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self { values: HashMap::new(), order: VecDeque::new(), capacity: None, stats: Stats::default() }
+    }
+
+    // Holds at most `capacity` values, dropping the oldest ones first
+    pub fn bounded(capacity: usize) -> Self {
+        Self { capacity: Some(capacity), ..Self::new() }
+    }
+
+    // The value cached under `key`, or the one `f` computes, which is cached
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = f(self);
+        self.insert(key, value.clone());
+
+        value
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let Some(capacity) = self.capacity else {
+            self.values.insert(key, value);
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
+
+        // The recursive calls may have cached the same key meanwhile
+        if self.values.insert(key.clone(), value).is_none() {
+            self.order.push_back(key);
+        }
+        while self.values.len() > capacity {
+            let oldest = self.order.pop_front().expect("every cached key is in the order");
+            self.values.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    // Drops the values but keeps the statistics, for keys only meaningful within one input
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.order.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(memo.stats(), Stats { hits: 87, misses: 89, evictions: 0 });
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(memo.stats().hits, 88);
+    }
+
+    #[test]
+    fn keeps_bounded_caches_under_their_capacity() {
+        let mut memo = Memo::bounded(3);
+
+        assert_eq!(fibonacci(40, &mut memo), 102_334_155);
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.stats().evictions, 36);
+
+        let mut nothing = Memo::bounded(0);
+        assert_eq!(fibonacci(10, &mut nothing), 55);
+        assert!(nothing.is_empty());
+    }
+
+    #[test]
+    fn clears_the_values_but_not_the_stats() {
+        let mut memo = Memo::new();
+        fibonacci(10, &mut memo);
+        memo.clear();
+
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().misses, 9);
+        assert!((memo.stats().hit_rate() - 7.0 / 16.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,71 @@
+use std::{collections::BTreeMap, str::FromStr};
+use crate::ParseError;
+
+// Named settings a solution reads on top of its input (step counts, test areas, ...), given on the
+// command line as `--param name=value` or in a fixture. Solutions take the ones they know about and
+// whatever is left is rejected, so that a typo does not silently fall back to the default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    pub fn take<T: FromStr>(&mut self, name: &str, default: T) -> Result<T, ParseError> {
+        match self.0.remove(name) {
+            None => Ok(default),
+            Some(value) => value
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::new(format!("invalid value `{}` for parameter `{}`", value, name))),
+        }
+    }
+
+    pub fn finish(self) -> Result<(), ParseError> {
+        match self.0.into_keys().next() {
+            None => Ok(()),
+            Some(name) => Err(ParseError::new(format!("unknown parameter `{}`", name))),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().map(|(name, value)| (name.into(), value.into())).collect())
+    }
+}
+
+// The settings of one solution, built from `Params` with a default for everything left unset
+pub trait Parameters: Sized {
+    fn take(params: &mut Params) -> Result<Self, ParseError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_known_parameters_and_rejects_the_rest() {
+        let mut params: Params = [("steps", "6"), ("stpes", "7")].into_iter().collect();
+
+        assert_eq!(params.take("steps", 64), Ok(6));
+        assert_eq!(params.take("cycles", 10), Ok(10));
+        assert_eq!(params.finish().unwrap_err().message, "unknown parameter `stpes`");
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let mut params: Params = [("steps", "six")].into_iter().collect();
+
+        assert_eq!(params.take::<usize>("steps", 64).unwrap_err().message, "invalid value `six` for parameter `steps`");
+    }
+}
@@ -0,0 +1,150 @@
+use std::{convert::Infallible, error::Error, fmt, str::FromStr};
+
+// Parsers only see the part of the input they are responsible for, so errors remember where the
+// offending text sits in memory and get their line and column once the whole input is known, see
+// `ParseError::locate`. This works as long as parsers hand around slices of the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+    address: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { line: 0, column: 0, message: message.into(), address: None }
+    }
+
+    pub fn at(token: &str, message: impl Into<String>) -> Self {
+        Self::new(message).with_location(token)
+    }
+
+    // Points the error at `token` unless it already points somewhere more precise
+    pub fn with_location(mut self, token: &str) -> Self {
+        if self.address.is_none() {
+            self.address = Some(token.as_ptr() as usize);
+        }
+        self
+    }
+
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some(address) = self.address else {
+            return self;
+        };
+
+        let start = input.as_ptr() as usize;
+        if address < start || address > start + input.len() {
+            return self;
+        }
+
+        let before = &input[..address - start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        self.line = before.matches('\n').count() + 1;
+        self.column = before[line_start..].chars().count() + 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<Infallible> for ParseError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::at(token, format!("expected a number, found `{}`", token)))
+}
+
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::at(s, format!("expected `{}` in `{}`", delimiter, s)))
+}
+
+pub fn unexpected(c: char) -> ParseError {
+    ParseError::new(format!("unexpected character `{}`", c))
+}
+
+// Parses `input`, writes the model back as puzzle text with `render` and parses that again, which
+// must give the same model (the text itself may differ, in its spacing or the order of its lines)
+pub fn round_trip<T, P, R>(input: &str, parse: P, render: R) -> Result<(), String>
+where
+    T: PartialEq + fmt::Debug,
+    P: Fn(&str) -> Result<T, ParseError>,
+    R: Fn(&T) -> String,
+{
+    let model = parse(input).map_err(|e| format!("could not parse the input: {}", e.locate(input)))?;
+    let rendered = render(&model);
+    let again = parse(&rendered).map_err(|e| format!("could not parse the rendered input: {}\n{}", e.locate(&rendered), rendered))?;
+
+    if again != model {
+        return Err(format!("the rendered input parses to another model\n{}\n{:?}\n{:?}", rendered, model, again));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens_in_the_input() {
+        let input = "1 2 3\n4 x 6\n";
+        let token = &input[8..9];
+
+        let error = ParseError::at(token, "bad").locate(input);
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.to_string(), "line 2, column 3: bad");
+    }
+
+    #[test]
+    fn keeps_the_most_precise_location() {
+        let input = "abc def";
+
+        let error = number::<u32>(&input[4..])
+            .unwrap_err()
+            .with_location(input)
+            .locate(input);
+
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn parses_rendered_models_again() {
+        let numbers = |s: &str| s.split_whitespace().map(number).collect::<Result<Vec<u32>, _>>();
+        let spaced = |numbers: &Vec<u32>| numbers.iter().map(|n| format!("{}  ", n)).collect::<String>();
+        let summed = |numbers: &Vec<u32>| numbers.iter().sum::<u32>().to_string();
+
+        assert_eq!(round_trip("1 2 3\n", numbers, spaced), Ok(()));
+        assert!(round_trip("1 2 3\n", numbers, summed).unwrap_err().starts_with("the rendered input parses to another model\n6\n"));
+        assert!(round_trip("1 x\n", numbers, spaced).unwrap_err().starts_with("could not parse the input: line 1, column 3"));
+    }
+
+    #[test]
+    fn leaves_foreign_tokens_unlocated() {
+        let other = String::from("x");
+        let error = ParseError::at(&other, "bad").locate("some input");
+
+        assert_eq!(error.line, 0);
+        assert_eq!(error.to_string(), "bad");
+    }
+}
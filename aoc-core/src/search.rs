@@ -0,0 +1,290 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+// Cost
+// -----------------------------------------------------------------------------
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(
+            impl Cost for $t {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_cost!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Path
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+// Breadth-first search
+// -----------------------------------------------------------------------------
+
+// Distance in steps from `start` to every reachable state
+pub fn bfs<S, F, I>(start: S, mut successors: F) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue: VecDeque<S> = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(distance + 1);
+            }
+        }
+    }
+
+    distances
+}
+
+// Fewest steps from `start` to the first state satisfying `is_goal`, both ends included
+pub fn bfs_path<S, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::from([(start.clone(), None)]);
+    let mut queue: VecDeque<S> = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(Some(state.clone()));
+            }
+        }
+    }
+
+    None
+}
+
+// Cheapest paths
+// -----------------------------------------------------------------------------
+pub fn dijkstra<S, C, F, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::ZERO, is_goal)
+}
+
+// `heuristic` must never overestimate the remaining cost, or the path found may not be the cheapest
+pub fn astar<S, C, F, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    // Every state seen so far, with its best known cost and the index of its parent
+    let mut nodes: Vec<(S, C, Option<usize>)> = vec![];
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut heap: BinaryHeap<Reverse<(C, C, usize)>> = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start) {
+            heap.push(Reverse((heuristic(entry.key()), C::ZERO, nodes.len())));
+            nodes.push((entry.key().clone(), C::ZERO, None));
+            entry.insert(nodes.len() - 1);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > nodes[index].1 {
+            continue;
+        }
+
+        if is_goal(&nodes[index].0) {
+            let mut states = vec![];
+            let mut current = Some(index);
+            while let Some(i) = current {
+                states.push(nodes[i].0.clone());
+                current = nodes[i].2;
+            }
+            states.reverse();
+
+            return Some(Path { states, cost });
+        }
+
+        for (next, step_cost) in successors(&nodes[index].0) {
+            let next_cost = cost + step_cost;
+
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    nodes.push((entry.key().clone(), next_cost, Some(index)));
+                    *entry.insert(nodes.len() - 1)
+                },
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= nodes[next_index].1 {
+                        continue;
+                    }
+                    nodes[next_index].1 = next_cost;
+                    nodes[next_index].2 = Some(index);
+                    next_index
+                },
+            };
+
+            heap.push(Reverse((next_cost + heuristic(&nodes[next_index].0), next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+// Longest simple path
+// -----------------------------------------------------------------------------
+
+// Exhaustive depth-first search over every path that never revisits a state. Goal states end a
+// path, they are never walked through.
+pub fn longest_path<S, C, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    if is_goal(&start) {
+        return Some(Path { states: vec![start], cost: C::ZERO });
+    }
+
+    let mut longest: Option<Path<S, C>> = None;
+    let mut visited: HashSet<S> = HashSet::from([start.clone()]);
+    let mut stack: Vec<(I::IntoIter, C)> = vec![(successors(&start).into_iter(), C::ZERO)];
+    let mut path: Vec<S> = vec![start];
+
+    while let Some((next_states, cost)) = stack.last_mut() {
+        let cost = *cost;
+
+        let Some((next, step_cost)) = next_states.next() else {
+            stack.pop();
+            visited.remove(&path.pop().unwrap());
+            continue;
+        };
+
+        if visited.contains(&next) {
+            continue;
+        }
+
+        let next_cost = cost + step_cost;
+
+        if is_goal(&next) {
+            if longest.as_ref().is_none_or(|longest| next_cost > longest.cost) {
+                let mut states = path.clone();
+                states.push(next);
+                longest = Some(Path { states, cost: next_cost });
+            }
+            continue;
+        }
+
+        visited.insert(next.clone());
+        stack.push((successors(&next).into_iter(), next_cost));
+        path.push(next);
+    }
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2
+    // |       |
+    // 3 ----- 4 - 5
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 4)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (4, 1)],
+            3 => vec![(0, 4), (4, 1)],
+            4 => vec![(2, 1), (3, 1), (5, 7)],
+            5 => vec![(4, 7)],
+            _ => unreachable!(),
+        }
+    }
+
+    fn neighbours(node: &u8) -> Vec<u8> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let distances = bfs(0, neighbours);
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&4], 2);
+        assert_eq!(distances[&5], 3);
+
+        assert_eq!(bfs_path(0, neighbours, |n| *n == 4).unwrap().len(), 3);
+        assert_eq!(bfs_path(0, neighbours, |n| *n == 9), None);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_find_the_cheapest_path() {
+        let path = dijkstra([0], edges, |n| *n == 5).unwrap();
+        assert_eq!(path, Path { states: vec![0, 1, 2, 4, 5], cost: 10 });
+
+        let path = astar([0], edges, |n| 5 - u32::from(*n), |n| *n == 5).unwrap();
+        assert_eq!(path.cost, 10);
+
+        assert_eq!(dijkstra([3, 5], edges, |n| *n == 4).unwrap().cost, 1);
+        assert_eq!(dijkstra([0], edges, |n| *n == 9), None);
+    }
+
+    #[test]
+    fn longest_path_never_revisits_a_state() {
+        let path = longest_path(0, edges, |n| *n == 5).unwrap();
+        assert_eq!(path, Path { states: vec![0, 3, 4, 5], cost: 12 });
+
+        assert_eq!(longest_path(0, edges, |n| *n == 9), None);
+    }
+}
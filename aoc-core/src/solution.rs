@@ -0,0 +1,108 @@
+use std::{marker::PhantomData, time::{Duration, Instant}};
+use crate::{Answer, Parameters, Params, ParseError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub trait Solution: Sync {
+    fn timed(&self, input: &str, params: &Params) -> Result<(Answer, Timings), ParseError>;
+
+    fn solve(&self, input: &str, params: &Params) -> Result<Answer, ParseError> {
+        self.timed(input, params).map(|(answer, _)| answer)
+    }
+}
+
+// What a part returns: its answer or, when the input lacks something only this part needs (both
+// parts of a day share their parsing), why it cannot answer
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, ParseError>;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Answer, ParseError> {
+        Ok(self)
+    }
+}
+
+impl IntoAnswer for Result<Answer, ParseError> {
+    fn into_answer(self) -> Result<Answer, ParseError> {
+        self
+    }
+}
+
+// A solution split in a `parse` stage turning the raw input into the puzzle model and a `solve`
+// stage answering one part of the puzzle from that model, so that both can be timed on their own
+pub struct Stages<P, S>(pub P, pub S);
+
+impl<P, S> Stages<P, S> {
+    // Ties the argument of `solve` to what `parse` returns, so that `solve` can be a closure
+    // borrowing the model as it likes (a `&[T]` out of a `Vec<T>`)
+    pub const fn new<T, R>(parse: P, solve: S) -> Self
+    where
+        P: Fn(&str) -> Result<T, ParseError>,
+        S: Fn(&T) -> R,
+    {
+        Self(parse, solve)
+    }
+}
+
+impl<P, S, T, R> Solution for Stages<P, S>
+where
+    P: Fn(&str) -> Result<T, ParseError> + Sync,
+    S: Fn(&T) -> R + Sync,
+    R: IntoAnswer,
+{
+    fn timed(&self, input: &str, params: &Params) -> Result<(Answer, Timings), ParseError> {
+        params.clone().finish()?;
+
+        timed(input, &self.0, |parsed| (self.1)(parsed))
+    }
+}
+
+// Same as `Stages` for a `solve` stage that also takes the settings `C` of the puzzle
+pub struct Tuned<P, S, C>(pub P, pub S, pub PhantomData<fn() -> C>);
+
+impl<P, S, C> Tuned<P, S, C> {
+    pub const fn new<T, R>(parse: P, solve: S) -> Self
+    where
+        P: Fn(&str) -> Result<T, ParseError>,
+        S: Fn(&T, C) -> R,
+    {
+        Self(parse, solve, PhantomData)
+    }
+}
+
+impl<P, S, T, R, C> Solution for Tuned<P, S, C>
+where
+    P: Fn(&str) -> Result<T, ParseError> + Sync,
+    S: Fn(&T, C) -> R + Sync,
+    R: IntoAnswer,
+    C: Parameters,
+{
+    fn timed(&self, input: &str, params: &Params) -> Result<(Answer, Timings), ParseError> {
+        let mut params = params.clone();
+        let config = C::take(&mut params)?;
+        params.finish()?;
+
+        timed(input, &self.0, |parsed| (self.1)(parsed, config))
+    }
+}
+
+fn timed<T, R: IntoAnswer>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+    solve: impl FnOnce(&T) -> R,
+) -> Result<(Answer, Timings), ParseError> {
+    let start = Instant::now();
+    let parsed = tracing::info_span!("parse", bytes = input.len()).in_scope(|| parse(input).map_err(|e| e.locate(input)))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = tracing::info_span!("solve").in_scope(|| solve(&parsed).into_answer())?;
+    let solve = start.elapsed();
+
+    Ok((answer, Timings { parse, solve }))
+}
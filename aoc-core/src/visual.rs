@@ -0,0 +1,157 @@
+use std::fmt;
+use crate::{Grid, Position};
+
+// Color
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const DIM: Self = Self::rgb(90, 90, 90);
+    pub const WHITE: Self = Self::rgb(230, 230, 230);
+    pub const RED: Self = Self::rgb(220, 50, 47);
+    pub const GREEN: Self = Self::rgb(80, 200, 80);
+    pub const BLUE: Self = Self::rgb(38, 139, 210);
+    pub const YELLOW: Self = Self::rgb(240, 200, 40);
+    pub const CYAN: Self = Self::rgb(42, 190, 180);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    // The color `t` of the way (clamped to 0..=1) from `self` to `other`
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+        Self::rgb(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b))
+    }
+}
+
+// Cell
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Self { glyph, color }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ', Color::BLACK)
+    }
+}
+
+// Frame
+// -----------------------------------------------------------------------------
+
+// A picture of a grid simulation: the terminal shows the glyphs in their color, images only the
+// colors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Self { cells, caption: String::new() }
+    }
+
+    pub fn from_grid<T, F>(grid: &Grid<T>, mut cell: F) -> Self
+    where
+        F: FnMut(Position, &T) -> Cell,
+    {
+        let mut cells = Grid::new(grid.rows(), grid.cols(), Cell::default());
+        for (position, value) in grid.iter() {
+            cells[position] = cell(position, value);
+        }
+
+        Self::new(cells)
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    // The frame with 24-bit color escapes, the color is only set again when it changes
+    pub fn ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for row in self.cells.iter_rows() {
+            let mut color: Option<Color> = None;
+
+            for cell in row {
+                if color != Some(cell.color) {
+                    ansi.push_str(&format!("\x1b[38;2;{};{};{}m", cell.color.r, cell.color.g, cell.color.b));
+                    color = Some(cell.color);
+                }
+                ansi.push(cell.glyph);
+            }
+            ansi.push_str("\x1b[0m\n");
+        }
+
+        if !self.caption.is_empty() {
+            ansi.push_str(&self.caption);
+            ansi.push('\n');
+        }
+
+        ansi
+    }
+}
+
+// Only the glyphs
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.iter_rows() {
+            writeln!(f, "{}", row.iter().map(|cell| cell.glyph).collect::<String>())?;
+        }
+
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid: Grid<char> = Grid::from_rows(vec![vec!['#', '.', '.'], vec!['.', '.', '#']]).unwrap();
+
+        Frame::from_grid(&grid, |_, &c| Cell::new(c, if c == '#' { Color::RED } else { Color::DIM }))
+    }
+
+    #[test]
+    fn displays_the_glyphs() {
+        assert_eq!(frame().with_caption("step 1").to_string(), "#..\n..#\nstep 1\n");
+    }
+
+    #[test]
+    fn sets_the_color_only_when_it_changes() {
+        let (red, dim) = ("\x1b[38;2;220;50;47m", "\x1b[38;2;90;90;90m");
+
+        assert_eq!(frame().ansi(), format!("{red}#{dim}..\x1b[0m\n{dim}..{red}#\x1b[0m\n"));
+    }
+
+    #[test]
+    fn mixes_colors() {
+        assert_eq!(Color::BLACK.mix(Color::rgb(200, 100, 10), 0.5), Color::rgb(100, 50, 5));
+        assert_eq!(Color::BLACK.mix(Color::WHITE, 2.0), Color::WHITE);
+    }
+}
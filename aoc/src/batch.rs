@@ -0,0 +1,117 @@
+use std::{collections::{hash_map, HashMap}, fs, io, path::{Path, PathBuf}};
+use crate::{calendar::{self, Report, Status}, ledger::Ledger, read_input, registry::Entry, store::Store};
+
+// Runs the parts of a day against several inputs, the files of a directory or a glob and the inputs
+// of every account, to catch the solutions only right for the input they were written against.
+
+// An input of the batch, its answers are checked against the ledger of its store
+#[derive(Debug, Clone)]
+pub struct Source {
+    pub name: String,
+    pub path: PathBuf,
+    pub store: Store,
+}
+
+impl Source {
+    // The file itself, the files of a directory, or the files matching a pattern with `*` and `?` in
+    // its file name (`inputs/day-21-*.txt`), sorted by name
+    pub fn files(pattern: &Path, store: &Store) -> io::Result<Vec<Self>> {
+        let source = |path: PathBuf| Self { name: path.display().to_string(), path, store: store.clone() };
+
+        if pattern.is_dir() {
+            return Ok(list(pattern, |_| true)?.into_iter().map(source).collect());
+        }
+
+        match pattern.file_name().and_then(|name| name.to_str()) {
+            Some(glob) if glob.contains(['*', '?']) => {
+                let dir = match pattern.parent() {
+                    Some(dir) if dir != Path::new("") => dir,
+                    _ => Path::new("."),
+                };
+                let glob: Vec<char> = glob.chars().collect();
+
+                Ok(list(dir, |name| matches(&glob, &name.chars().collect::<Vec<char>>()))?.into_iter().map(source).collect())
+            },
+            _ => Ok(vec![source(pattern.to_path_buf())]),
+        }
+    }
+
+    // The input of the day stored for every account under `root`, the accounts without one are left
+    // out
+    pub fn accounts(root: &Path, year: u16, day: u8) -> io::Result<Vec<Self>> {
+        Ok(Store::accounts(root)?
+            .into_iter()
+            .map(|account| {
+                let store = Store::new(root, Some(&account));
+                Self { path: store.path(year, day), name: account, store }
+            })
+            .filter(|source| source.path.is_file())
+            .collect())
+    }
+}
+
+// The reports of the parts for one input, in the order of the entries
+#[derive(Debug)]
+pub struct Row {
+    pub source: String,
+    pub reports: Vec<Report>,
+}
+
+// Runs one input after the other so that their times compare, a part failing on an input only
+// fails its own report
+pub fn run_all(entries: &[&Entry], sources: &[Source]) -> io::Result<Vec<Row>> {
+    let mut rows: Vec<Row> = vec![];
+
+    for source in sources {
+        let reports = match read_input(Some(&source.path)) {
+            Ok(input) => {
+                let mut ledgers: HashMap<u16, Ledger> = HashMap::new();
+                let mut reports: Vec<Report> = vec![];
+
+                for entry in entries {
+                    if let hash_map::Entry::Vacant(ledger) = ledgers.entry(entry.year) {
+                        ledger.insert(Ledger::open(&source.store, entry.year)?);
+                    }
+                    reports.push(calendar::solve(entry, &input, &ledgers[&entry.year]));
+                }
+
+                reports
+            },
+            Err(e) => entries
+                .iter()
+                .map(|entry| Report::new(entry, None, Status::Unreadable(io::Error::new(e.kind(), e.to_string())), None))
+                .collect(),
+        };
+
+        rows.push(Row { source: source.name.clone(), reports });
+    }
+
+    Ok(rows)
+}
+
+fn list(dir: &Path, mut keep: impl FnMut(&str) -> bool) -> io::Result<Vec<PathBuf>> {
+    let mut paths: Vec<PathBuf> = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+
+        if path.is_file() && !name.starts_with('.') && keep(name) {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+// `*` stands for any run of characters, `?` for any one
+fn matches(glob: &[char], name: &[char]) -> bool {
+    match (glob.first(), name.first()) {
+        (None, _) => name.is_empty(),
+        (Some('*'), _) => matches(&glob[1..], name) || (!name.is_empty() && matches(glob, &name[1..])),
+        (Some('?'), Some(_)) => matches(&glob[1..], &name[1..]),
+        (Some(g), Some(n)) if g == n => matches(&glob[1..], &name[1..]),
+        _ => false,
+    }
+}
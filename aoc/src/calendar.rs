@@ -0,0 +1,108 @@
+use std::{any::Any, collections::{hash_map, HashMap}, fmt, io, panic, path::Path, time::{Duration, Instant}};
+use rayon::prelude::*;
+use aoc_core::{Answer, Params, ParseError};
+use crate::{find_input, ledger::{Ledger, Verdict}, overflow::{self, Overflow}, read_input, registry::Entry, store::Store};
+
+// How a part fared, its answer is checked against the ledger of its year
+#[derive(Debug)]
+pub enum Status {
+    Ok,
+    Mismatch(Verdict),
+    Unparsed(ParseError),
+    Panicked(String),
+    Overflowed(Overflow),
+    Unreadable(io::Error),
+    NoInput,
+}
+
+impl Status {
+    // A part without input is skipped rather than failed
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Self::Ok | Self::NoInput)
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    // None when the part could not run
+    pub elapsed: Option<Duration>,
+}
+
+impl Report {
+    pub fn new(entry: &Entry, answer: Option<Answer>, status: Status, elapsed: Option<Duration>) -> Self {
+        Self { year: entry.year, day: entry.day, part: entry.part, answer, status, elapsed }
+    }
+}
+
+// Runs the parts concurrently on the current rayon pool, a part panicking only fails its own report.
+// The reports come back in the order of the entries
+pub fn run_all(entries: &[&Entry], store: &Store, root: &Path) -> io::Result<Vec<Report>> {
+    let mut ledgers: HashMap<u16, Ledger> = HashMap::new();
+    for entry in entries {
+        if let hash_map::Entry::Vacant(ledger) = ledgers.entry(entry.year) {
+            ledger.insert(Ledger::open(store, entry.year)?);
+        }
+    }
+
+    Ok(entries
+        .par_iter()
+        .map(|entry| run(entry, store, root, &ledgers[&entry.year]))
+        .collect())
+}
+
+fn run(entry: &Entry, store: &Store, root: &Path, ledger: &Ledger) -> Report {
+    let Some(path) = find_input(store, root, entry.year, entry.day, entry.part) else {
+        return Report::new(entry, None, Status::NoInput, None);
+    };
+
+    match read_input(Some(&path)) {
+        Ok(input) => solve(entry, &input, ledger),
+        Err(e) => Report::new(entry, None, Status::Unreadable(e), None),
+    }
+}
+
+// Runs the part on the input and checks its answer against the ledger, a panic only fails the report
+pub fn solve(entry: &Entry, input: &str, ledger: &Ledger) -> Report {
+    let _span = entry.span().entered();
+    let start = Instant::now();
+    let solved = panic::catch_unwind(panic::AssertUnwindSafe(|| overflow::catch(|| entry.solution.solve(input, &Params::new()))));
+    let elapsed = Some(start.elapsed());
+
+    match solved {
+        Ok(Ok(Ok(answer))) => {
+            let verdict = ledger.check(entry.day, entry.part, input, &answer.to_string());
+            let status = if verdict.is_wrong() { Status::Mismatch(verdict) } else { Status::Ok };
+
+            Report::new(entry, Some(answer), status, elapsed)
+        },
+        Ok(Ok(Err(e))) => Report::new(entry, None, Status::Unparsed(e), elapsed),
+        Ok(Err(overflow)) => Report::new(entry, None, Status::Overflowed(overflow), elapsed),
+        Err(payload) => Report::new(entry, None, Status::Panicked(panic_message(payload)), elapsed),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("no message", |message| message).to_string(),
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Mismatch(verdict) => write!(f, "mismatch, {}", verdict),
+            Self::Unparsed(e) => write!(f, "could not parse input: {}", e),
+            Self::Panicked(message) => write!(f, "panicked, {}", message),
+            Self::Overflowed(overflow) => write!(f, "overflowed, {}", overflow),
+            Self::Unreadable(e) => write!(f, "could not read input: {}", e),
+            Self::NoInput => write!(f, "no input"),
+        }
+    }
+}
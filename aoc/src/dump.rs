@@ -0,0 +1,67 @@
+use serde_json::{Map, Value};
+use aoc_core::{Params, ParseError};
+use crate::registry::{Dump, Entry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Stage {
+    // The model `parse` makes of the input
+    Parsed,
+    // The answers of the parts, with the state the day built on the way if it keeps one
+    Solved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    // Indented, one value per line so that two dumps diff well
+    Json,
+    // On a single line
+    Compact,
+}
+
+// `serialize` goes through a `Value`, whose objects keep their keys sorted so that the hash maps of
+// the models come out in the same order on every run
+pub fn to_json<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+    serialize: impl Fn(&T) -> serde_json::Result<Value>,
+) -> Result<Value, String> {
+    let parsed = parse(input).map_err(|e| e.locate(input).to_string())?;
+
+    serialize(&parsed).map_err(|e| format!("could not serialize the model: {}", e))
+}
+
+// `parts` are the ones answered at the `solved` stage, all taking the same `params`. A part that
+// cannot answer (the input lacks what it needs, a setting is not one of its own) gets its reason in
+// `errors` rather than failing the whole dump.
+pub fn dump(dump: &Dump, parts: &[&Entry], input: &str, stage: Stage, params: &Params) -> Result<Value, String> {
+    let parsed = (dump.parsed)(input)?;
+    if stage == Stage::Parsed {
+        return Ok(parsed);
+    }
+
+    let (mut answers, mut errors) = (Map::new(), Map::new());
+    for entry in parts {
+        match entry.span().in_scope(|| entry.solution.solve(input, params)) {
+            Ok(answer) => answers.insert(entry.part.to_string(), Value::String(answer.to_string())),
+            Err(e) => errors.insert(entry.part.to_string(), Value::String(e.to_string())),
+        };
+    }
+
+    let mut solved = Map::from_iter([("answers".to_string(), Value::Object(answers))]);
+    if !errors.is_empty() {
+        solved.insert("errors".to_string(), Value::Object(errors));
+    }
+    if let Some(state) = dump.solved {
+        solved.insert("state".to_string(), state(input)?);
+    }
+
+    Ok(Value::Object(solved))
+}
+
+pub fn write(value: &Value, format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(value),
+        Format::Compact => serde_json::to_string(value),
+    }
+    .expect("a JSON value always serializes")
+}
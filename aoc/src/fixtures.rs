@@ -0,0 +1,150 @@
+use std::{fmt, fs, io, panic, path::{Path, PathBuf}};
+use serde::Deserialize;
+use aoc_core::{Params, ParseError};
+use crate::registry;
+
+// Every puzzle directory (`<year>/<day>/puzzle-<part>`) can ship a `fixtures.toml` sidecar pairing
+// its example inputs with the answer given in the puzzle description:
+//
+// [[fixture]]
+// input = "test.txt"
+// answer = 4361
+// ignore = "optional reason why this example is known to fail"
+// params = { steps = 6 }  # optional, see `aoc_core::Params`
+pub const SIDECAR: &str = "fixtures.toml";
+
+#[derive(Debug, Deserialize)]
+struct Sidecar {
+    #[serde(default)]
+    fixture: Vec<SidecarEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SidecarEntry {
+    input: String,
+    answer: toml::Value,
+    ignore: Option<String>,
+    #[serde(default)]
+    params: toml::Table,
+}
+
+// Fixture
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Fixture {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: String,
+    pub ignore: Option<String>,
+    pub params: Params,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed(String),
+    Unparsed(ParseError),
+    Panicked,
+    Ignored(String),
+    Unregistered,
+}
+
+impl Fixture {
+    pub fn run(&self) -> io::Result<Outcome> {
+        if let Some(reason) = &self.ignore {
+            return Ok(Outcome::Ignored(reason.clone()));
+        }
+
+        let Some(entry) = registry::find(self.year, self.day, self.part) else {
+            return Ok(Outcome::Unregistered);
+        };
+
+        let input = fs::read_to_string(&self.input)?;
+
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| entry.solution.solve(&input, &self.params))) {
+            Ok(Ok(answer)) if answer.to_string() == self.answer => Ok(Outcome::Passed),
+            Ok(Ok(answer)) => Ok(Outcome::Failed(answer.to_string())),
+            Ok(Err(e)) => Ok(Outcome::Unparsed(e)),
+            Err(_) => Ok(Outcome::Panicked),
+        }
+    }
+}
+
+impl fmt::Display for Fixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file_name = self.input.file_name().unwrap_or_default().to_string_lossy();
+        write!(f, "{} day {:02} part {} ({})", self.year, self.day, self.part, file_name)
+    }
+}
+
+// Discovery
+// -----------------------------------------------------------------------------
+pub fn discover(root: &Path) -> io::Result<Vec<Fixture>> {
+    let mut fixtures: Vec<Fixture> = vec![];
+
+    for (year, year_dir) in numbered_entries(root, "")? {
+        for (day, day_dir) in numbered_entries(&year_dir, "")? {
+            for (part, puzzle_dir) in numbered_entries(&day_dir, "puzzle-")? {
+                let sidecar = puzzle_dir.join(SIDECAR);
+                if !sidecar.is_file() {
+                    continue;
+                }
+
+                let sidecar: Sidecar = toml::from_str(&fs::read_to_string(&sidecar)?)
+                    .map_err(|e| io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {}", sidecar.display(), e)
+                    ))?;
+
+                for entry in sidecar.fixture {
+                    fixtures.push(Fixture {
+                        year: year as u16,
+                        day: day as u8,
+                        part: part as u8,
+                        input: puzzle_dir.join(entry.input),
+                        answer: plain(entry.answer),
+                        ignore: entry.ignore,
+                        params: entry.params.into_iter().map(|(name, value)| (name, plain(value))).collect(),
+                    });
+                }
+            }
+        }
+    }
+
+    fixtures.sort_by_key(|f| (f.year, f.day, f.part));
+    Ok(fixtures)
+}
+
+// Strings without their quotes, everything else as written
+fn plain(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+// Directories named `<prefix><number>`, e.g. `2023`, `05` or `puzzle-1`
+fn numbered_entries(dir: &Path, prefix: &str) -> io::Result<Vec<(u32, PathBuf)>> {
+    let mut entries: Vec<(u32, PathBuf)> = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let number = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|name| name.parse::<u32>().ok());
+
+        if let Some(number) = number {
+            entries.push((number, path));
+        }
+    }
+
+    Ok(entries)
+}
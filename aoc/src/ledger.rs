@@ -0,0 +1,198 @@
+use std::{fmt, fs, io, path::PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::store::{sha256, Store};
+
+// Every year of the input store keeps the answers given for its inputs in a `answers.toml`:
+//
+// [[answer]]
+// day = 5
+// part = 1
+// input = "<SHA-256 of the input>"
+// answer = "35"  # once the answer is accepted
+//
+// [[answer.rejected]]
+// guess = "40"
+// hint = "too-high"  # or "too-low", when the puzzle gave one
+pub const LEDGER: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub guess: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected: Vec<Rejected>,
+}
+
+// What the ledger knows about an answer before it is submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Unknown,
+    Confirmed,
+    Disagrees { confirmed: String },
+    Rejected { hint: Option<Hint> },
+    // A guess with a hint rules out every answer on its wrong side
+    OutOfRange { guess: String, hint: Hint },
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        !matches!(self, Self::Unknown | Self::Confirmed)
+    }
+}
+
+// Ledger
+// -----------------------------------------------------------------------------
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LedgerFile {
+    #[serde(default, rename = "answer")]
+    records: Vec<Record>,
+}
+
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl Ledger {
+    pub fn open(store: &Store, year: u16) -> io::Result<Self> {
+        let path = store.dir().join(year.to_string()).join(LEDGER);
+
+        let file: LedgerFile = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => LedgerFile::default(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, records: file.records })
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn record(&self, day: u8, part: u8, input: &str) -> Option<&Record> {
+        let input = sha256(input.as_bytes());
+
+        self.records.iter().find(|r| r.day == day && r.part == part && r.input == input)
+    }
+
+    pub fn confirm(&mut self, day: u8, part: u8, input: &str, answer: &str) {
+        self.record_mut(day, part, input).answer = Some(answer.to_string());
+    }
+
+    pub fn reject(&mut self, day: u8, part: u8, input: &str, guess: &str, hint: Option<Hint>) {
+        let record = self.record_mut(day, part, input);
+
+        match record.rejected.iter_mut().find(|rejected| rejected.guess == guess) {
+            Some(rejected) => rejected.hint = hint.or(rejected.hint),
+            None => record.rejected.push(Rejected { guess: guess.to_string(), hint }),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &str) -> Verdict {
+        let Some(record) = self.record(day, part, input) else {
+            return Verdict::Unknown;
+        };
+
+        if let Some(confirmed) = &record.answer {
+            return if confirmed == answer {
+                Verdict::Confirmed
+            } else {
+                Verdict::Disagrees { confirmed: confirmed.clone() }
+            };
+        }
+
+        if let Some(rejected) = record.rejected.iter().find(|rejected| rejected.guess == answer) {
+            return Verdict::Rejected { hint: rejected.hint };
+        }
+
+        // Only numbers have a wrong side, answers too big for an i128 are not compared
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Verdict::Unknown;
+        };
+
+        for rejected in &record.rejected {
+            let (Some(hint), Ok(guess)) = (rejected.hint, rejected.guess.parse::<i128>()) else {
+                continue;
+            };
+
+            let out_of_range = match hint {
+                Hint::TooHigh => answer >= guess,
+                Hint::TooLow => answer <= guess,
+            };
+            if out_of_range {
+                return Verdict::OutOfRange { guess: rejected.guess.clone(), hint };
+            }
+        }
+
+        Verdict::Unknown
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut records = self.records.clone();
+        records.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+
+        let contents = toml::to_string(&LedgerFile { records })
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, contents)
+    }
+
+    fn record_mut(&mut self, day: u8, part: u8, input: &str) -> &mut Record {
+        let input = sha256(input.as_bytes());
+
+        let index = match self.records.iter().position(|r| r.day == day && r.part == part && r.input == input) {
+            Some(index) => index,
+            None => {
+                self.records.push(Record { day, part, input, answer: None, rejected: vec![] });
+                self.records.len() - 1
+            }
+        };
+
+        &mut self.records[index]
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "not in the ledger yet"),
+            Self::Confirmed => write!(f, "matches the confirmed answer"),
+            Self::Disagrees { confirmed } => write!(f, "disagrees with the confirmed answer {}", confirmed),
+            Self::Rejected { hint: Some(hint) } => write!(f, "was already rejected as {}", hint),
+            Self::Rejected { hint: None } => write!(f, "was already rejected"),
+            Self::OutOfRange { guess, hint } => write!(f, "cannot be right, {} was rejected as {}", guess, hint),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+pub mod batch;
+pub mod calendar;
+#[cfg(feature = "dump")]
+pub mod dump;
+pub mod fixtures;
+pub mod ledger;
+pub mod logging;
+pub mod overflow;
+pub mod registry;
+pub mod scaffold;
+pub mod store;
+pub mod table;
+pub mod timing;
+pub mod visual;
+
+use std::{fs, io::{self, Read}, path::{Path, PathBuf}};
+use store::Store;
+
+pub fn read_input(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) if path != Path::new("-") => fs::read_to_string(path),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+// The input of the store, or else the loose `<root>/<year>/<DD>/puzzle-<part>/input.txt` (days share
+// one input so the part 2 folder may not hold a copy of its own)
+pub fn find_input(store: &Store, root: &Path, year: u16, day: u8, part: u8) -> Option<PathBuf> {
+    let day_dir = root.join(year.to_string()).join(format!("{:02}", day));
+
+    [store.path(year, day)]
+        .into_iter()
+        .chain([part, 1].map(|part| day_dir.join(format!("puzzle-{}", part)).join("input.txt")))
+        .find(|path| path.is_file())
+}
@@ -0,0 +1,51 @@
+use std::{env, io};
+use tracing::Subscriber;
+use tracing_subscriber::{filter::{EnvFilter, ParseError}, fmt::{format::FmtSpan, MakeWriter}};
+
+// Filter like `info,aoc_2023_day_20=trace` taking over from `-v`, see `EnvFilter` for its syntax
+pub const FILTER_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    // One JSON object per line, with the spans the event happened in
+    Json,
+    Text,
+}
+
+// Traces are off unless asked for: `-v` shows the spans of the parts and the debug events of the
+// solutions, `-vv` every step of their simulations
+pub fn filter(verbosity: u8) -> Result<EnvFilter, ParseError> {
+    match env::var(FILTER_ENV) {
+        Ok(filter) => EnvFilter::try_new(filter),
+        Err(_) => EnvFilter::try_new(match verbosity {
+            0 => "off",
+            1 => "debug",
+            _ => "trace",
+        }),
+    }
+}
+
+// Spans report how long they were busy when they close
+pub fn subscriber<W>(filter: EnvFilter, format: Format, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(writer);
+
+    match format {
+        Format::Json => Box::new(builder.json().with_current_span(true).with_span_list(true).finish()),
+        Format::Text => Box::new(builder.with_ansi(false).finish()),
+    }
+}
+
+pub fn init(verbosity: u8, format: Format) -> Result<(), ParseError> {
+    let subscriber = subscriber(filter(verbosity)?, format, io::stderr);
+
+    // Only fails when a subscriber is already set, which then keeps the traces
+    let _ = tracing::subscriber::set_global_default(subscriber);
+
+    Ok(())
+}
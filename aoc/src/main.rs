@@ -0,0 +1,946 @@
+use std::{fs, io::{self, Write}, path::{Path, PathBuf}, process::ExitCode, time::{Duration, Instant}};
+use clap::{Parser, Subcommand};
+use rand::{rngs::StdRng, SeedableRng};
+use aoc::{batch, calendar, fixtures::{self, Outcome}, find_input, logging, overflow, read_input, registry, scaffold, table::Table, timing, visual};
+#[cfg(feature = "dump")]
+use aoc::dump;
+use aoc::ledger::{Hint, Ledger};
+use aoc::store::{HttpFetcher, Integrity, Store};
+use aoc_core::{Generated, Params};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions")]
+struct Cli {
+    /// Directory of the input store, `$XDG_DATA_HOME/aoc/inputs` by default
+    #[arg(long, global = true, env = "AOC_STORE")]
+    store: Option<PathBuf>,
+    /// Account whose inputs are used, when the store holds the inputs of several
+    #[arg(long, global = true, env = "AOC_ACCOUNT")]
+    account: Option<String>,
+    /// Show the traces of the solutions on stderr: `-v` their spans and debug events, `-vv` every
+    /// step of their simulations (`AOC_LOG` takes a filter like `aoc_2023_day_20=trace` instead)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Format of the traces
+    #[arg(long, global = true, value_enum, default_value_t = logging::Format::Json)]
+    log_format: logging::Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single part of a puzzle, or every part of a year with `--all`
+    Run {
+        year: u16,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        #[arg(required_unless_present = "all")]
+        part: Option<u8>,
+        /// Puzzle input file, taken from the input store when omitted (or else read from stdin like
+        /// with `-`)
+        input: Option<PathBuf>,
+        /// Print how long parsing and solving took on stderr
+        #[arg(long)]
+        time: bool,
+        /// Run the solution this many times and report min / median / max timings
+        #[arg(long, default_value_t = 1)]
+        runs: usize,
+        /// Puzzle setting overriding its default, e.g. `--param steps=6` (repeatable)
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Run every registered part of the year concurrently and print a summary
+        #[arg(long, conflicts_with_all = ["day", "part", "input", "time", "runs", "params"])]
+        all: bool,
+        /// Number of parts run at once with `--all`, one per core by default
+        #[arg(short, long, requires = "all")]
+        jobs: Option<usize>,
+        /// Directory holding the `<year>/<day>/puzzle-<part>/input.txt` inputs missing from the store
+        #[arg(long, default_value = ".", requires = "all")]
+        root: PathBuf,
+    },
+    /// Draw a part of a puzzle frame by frame in the terminal, or export its frames to an image
+    Visualise {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Puzzle input file, taken from the input store when omitted (or else read from stdin like
+        /// with `-`)
+        input: Option<PathBuf>,
+        /// Export to a `.gif`, an `.apng` or a `.png` still of the last frame instead
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        /// Milliseconds between two frames, the terminal waits for Enter between them without it
+        #[arg(long)]
+        delay: Option<u64>,
+        /// Pixels per cell of the exported images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+    },
+    /// Print the model of a puzzle input as JSON, or the answers and the state the day built
+    #[cfg(feature = "dump")]
+    Dump {
+        year: u16,
+        day: u8,
+        /// Puzzle input file, taken from the input store when omitted (or else read from stdin like
+        /// with `-`)
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = dump::Stage::Parsed)]
+        stage: dump::Stage,
+        #[arg(long, value_enum, default_value_t = dump::Format::Json)]
+        format: dump::Format,
+        /// Only answer this part at the `solved` stage
+        #[arg(long)]
+        part: Option<u8>,
+        /// Puzzle setting overriding its default, e.g. `--param steps=6` (repeatable)
+        #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Time every registered part of a year, or of a single day
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Directory holding the `<year>/<day>/puzzle-<part>/input.txt` inputs missing from the store
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Run a day against several inputs and print the answers and times of its parts side by side
+    Batch {
+        year: u16,
+        day: u8,
+        /// Only run this part
+        #[arg(long)]
+        part: Option<u8>,
+        /// Input files, directories of inputs or patterns like `inputs/day-21-*.txt`
+        #[arg(required_unless_present = "accounts")]
+        inputs: Vec<PathBuf>,
+        /// Run the stored input of every account of the store as well
+        #[arg(long)]
+        accounts: bool,
+    },
+    /// Make up a random input for a day, the seed and the answers known by construction are
+    /// printed on stderr
+    Generate {
+        year: u16,
+        day: u8,
+        /// Scale of the input, what it counts depends on the day (lines, side of a map, ...)
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the input, a random one when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+        /// File to write the input to instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        /// Make an input like the puzzle's own, with the layout the solutions of the day rely on
+        #[arg(long)]
+        puzzle_like: bool,
+    },
+    /// Time a part on random inputs of growing sizes
+    Scale {
+        year: u16,
+        day: u8,
+        part: u8,
+        #[arg(long, value_delimiter = ',', default_values_t = [10, 100, 1000])]
+        sizes: Vec<usize>,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[arg(long, default_value_t = 3)]
+        runs: usize,
+        /// Make inputs like the puzzle's own, with the layout the solutions of the day rely on
+        #[arg(long)]
+        puzzle_like: bool,
+    },
+    /// Check the example inputs against the answers in their fixtures.toml
+    Test {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Directory holding the `<year>/<day>/puzzle-<part>` folders
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Create the crate of a new day and register both of its parts in the runner
+    New {
+        year: u16,
+        day: u8,
+        /// Saved puzzle page to write the README.md of the parts from
+        #[arg(long)]
+        page: Option<PathBuf>,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Write the README.md of the parts of a day from its saved puzzle page
+    Readme {
+        year: u16,
+        day: u8,
+        page: PathBuf,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Manage the input store
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+    /// Record the answers given for the stored inputs, `aoc run` then flags the known wrong ones
+    Answer {
+        #[command(subcommand)]
+        command: AnswerCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Store the input of a puzzle, read from a file or stdin, or downloaded with `--fetch`
+    Add {
+        year: u16,
+        day: u8,
+        /// Puzzle input file, stdin is read when omitted or `-`
+        input: Option<PathBuf>,
+        /// Download the input with the session cookie of the account
+        #[arg(long, conflicts_with = "input")]
+        fetch: bool,
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+        /// Server to download the inputs from
+        #[arg(long, default_value = HttpFetcher::BASE_URL)]
+        url: String,
+    },
+    /// List the stored inputs
+    List,
+    /// Check the stored inputs against the checksums of their manifests
+    Verify,
+}
+
+#[derive(Subcommand)]
+enum AnswerCommand {
+    /// Record an accepted answer
+    Confirm {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+        /// Puzzle input the answer was given for, the stored input by default
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Record a rejected guess, with the hint given by the puzzle if any
+    Reject {
+        year: u16,
+        day: u8,
+        part: u8,
+        guess: String,
+        #[arg(long, conflicts_with = "too_low")]
+        too_high: bool,
+        #[arg(long)]
+        too_low: bool,
+        /// Puzzle input the guess was given for, the stored input by default
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(e) = logging::init(cli.verbose, cli.log_format) {
+        eprintln!("Invalid {} filter: {}", logging::FILTER_ENV, e);
+        return ExitCode::FAILURE;
+    }
+    let store_root = cli.store.unwrap_or_else(Store::default_root);
+    let store = Store::new(&store_root, cli.account.as_deref());
+
+    match cli.command {
+        Command::Run { year, all: true, jobs, root, .. } => run_all(&store, year, jobs, root),
+        Command::Run { year, day: Some(day), part: Some(part), input, time, runs, params, .. } => {
+            run(&store, year, day, part, input, time, runs, params.into_iter().collect())
+        },
+        Command::Run { .. } => unreachable!("clap requires the day and part without `--all`"),
+        Command::Visualise { year, day, part, input, out, delay, scale } => {
+            visualise(&store, year, day, part, input, out, delay.map(Duration::from_millis), scale)
+        },
+        #[cfg(feature = "dump")]
+        Command::Dump { year, day, input, stage, format, part, params } => {
+            dump(&store, year, day, input, stage, format, part, params.into_iter().collect())
+        },
+        Command::Bench { year, day, runs, root } => bench(&store, year, day, runs, root),
+        Command::Batch { year, day, part, inputs, accounts } => {
+            batch(&store, &store_root, year, day, part, &inputs, accounts)
+        },
+        Command::Generate { year, day, size, seed, out, puzzle_like } => generate(year, day, size, seed, out, puzzle_like),
+        Command::Scale { year, day, part, sizes, seed, runs, puzzle_like } => {
+            scale(year, day, part, &sizes, seed, runs, puzzle_like)
+        },
+        Command::Test { year, day, root } => test(year, day, root),
+        Command::New { year, day, page, root } => new(year, day, page, root),
+        Command::Readme { year, day, page, root } => readme(year, day, &page, &root),
+        Command::Input { command } => match command {
+            InputCommand::Add { year, day, input, fetch, session, url } => {
+                input_add(&store, year, day, input, fetch, session, url)
+            },
+            InputCommand::List => input_list(&store),
+            InputCommand::Verify => input_verify(&store),
+        },
+        Command::Answer { command } => match command {
+            AnswerCommand::Confirm { year, day, part, answer, input } => {
+                record_answer(&store, year, day, part, input, |ledger, input| ledger.confirm(day, part, input, &answer))
+            },
+            AnswerCommand::Reject { year, day, part, guess, too_high, too_low, input } => {
+                let hint = match (too_high, too_low) {
+                    (true, _) => Some(Hint::TooHigh),
+                    (_, true) => Some(Hint::TooLow),
+                    _ => None,
+                };
+                record_answer(&store, year, day, part, input, |ledger, input| ledger.reject(day, part, input, &guess, hint))
+            },
+        },
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    s
+        .split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .ok_or_else(|| format!("expected `<name>=<value>`, found `{}`", s))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run(store: &Store, year: u16, day: u8, part: u8, input: Option<PathBuf>, time: bool, runs: usize, params: Params) -> ExitCode {
+    let Some(entry) = registry::find(year, day, part) else {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part);
+        return ExitCode::FAILURE;
+    };
+
+    let input = match load_input(store, year, day, input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let benchmark = match overflow::catch(|| entry.span().in_scope(|| timing::bench(entry.solution, &input, &params, runs))) {
+        Ok(benchmark) => benchmark,
+        Err(overflow) => {
+            eprintln!("{} day {} part {} overflowed: {}", year, day, part, overflow);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match benchmark {
+        Ok(benchmark) => {
+            println!("{}", benchmark.answer);
+            if time || runs > 1 {
+                eprintln!("parse: {}", benchmark.parse);
+                eprintln!("solve: {}", benchmark.solve);
+            }
+            // The ledger holds the answers to the puzzle as given, not with other settings
+            if params.is_empty() {
+                check_answer(store, year, day, part, &input, &benchmark.answer.to_string());
+            }
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Invalid input or parameters: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_all(store: &Store, year: u16, jobs: Option<usize>, root: PathBuf) -> ExitCode {
+    let entries: Vec<&registry::Entry> = registry::ENTRIES.iter().filter(|e| e.year == year).collect();
+    if entries.is_empty() {
+        eprintln!("No solution registered for {}", year);
+        return ExitCode::FAILURE;
+    }
+
+    let pool = match rayon::ThreadPoolBuilder::new().num_threads(jobs.unwrap_or(0)).build() {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Could not start the thread pool: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let start = Instant::now();
+    let reports = match pool.install(|| calendar::run_all(&entries, store, &root)) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Could not open the ledger: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let elapsed = start.elapsed();
+
+    let mut table = Table::new(&["day", "part", "answer", "status", "time"]);
+    let (mut ok, mut failed, mut skipped) = (0, 0, 0);
+
+    for report in &reports {
+        match &report.status {
+            status if status.is_failure() => failed += 1,
+            calendar::Status::NoInput => skipped += 1,
+            _ => ok += 1,
+        }
+
+        table.push(&[
+            report.day.to_string(),
+            report.part.to_string(),
+            report.answer.as_ref().map(ToString::to_string).unwrap_or_default(),
+            report.status.to_string(),
+            report.elapsed.map(|elapsed| format!("{:.2?}", elapsed)).unwrap_or_default(),
+        ]);
+    }
+
+    print!("{}", table);
+    println!();
+    println!("{} ok, {} failed, {} without input in {:.2?}", ok, failed, skipped, elapsed);
+
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+// The given file, or else the stored input, or else stdin
+fn load_input(store: &Store, year: u16, day: u8, input: Option<PathBuf>) -> io::Result<String> {
+    match input {
+        Some(path) => read_input(Some(&path)),
+        None => store.get(year, day).and_then(|input| input.map_or_else(|| read_input(None), Ok)),
+    }
+}
+
+fn check_answer(store: &Store, year: u16, day: u8, part: u8, input: &str, answer: &str) {
+    match Ledger::open(store, year) {
+        Ok(ledger) => {
+            let verdict = ledger.check(day, part, input, answer);
+            if verdict.is_wrong() {
+                eprintln!("warning: {} {}", answer, verdict);
+            }
+        },
+        Err(e) => eprintln!("warning: could not check the answer against the ledger: {}", e),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn visualise(
+    store: &Store,
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<PathBuf>,
+    out: Option<PathBuf>,
+    delay: Option<Duration>,
+    scale: usize,
+) -> ExitCode {
+    let Some(visual) = registry::find_visual(year, day) else {
+        eprintln!("No visualisation registered for {} day {}", year, day);
+        return ExitCode::FAILURE;
+    };
+    if registry::find(year, day, part).is_none() {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part);
+        return ExitCode::FAILURE;
+    }
+
+    let out = match out {
+        Some(out) => match visual::Format::from_path(&out) {
+            Some(format) => Some((out, format)),
+            None => {
+                eprintln!("Cannot tell the image format of {}, expected a .gif, .apng or .png", out.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let frames = match load_input(store, year, day, input) {
+        Ok(input) => match (visual.frames)(&input, part) {
+            Ok(frames) => frames,
+            Err(e) => {
+                eprintln!("Invalid input: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(e) => {
+            eprintln!("Could not read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let shown = match out {
+        Some((out, format)) => {
+            visual::export(&frames, &out, format, scale, delay.unwrap_or(Duration::from_millis(100)))
+        },
+        None => {
+            // Stepping reads the terminal, stdin may well have been the input
+            let mut stdout = io::stdout().lock();
+            match fs::File::open("/dev/tty") {
+                Ok(tty) => visual::play(&frames, delay, &mut stdout, &mut io::BufReader::new(tty)),
+                Err(_) => visual::play(&frames, delay, &mut stdout, &mut io::stdin().lock()),
+            }
+        },
+    };
+
+    match shown {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Could not show the frames: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(feature = "dump")]
+#[allow(clippy::too_many_arguments)]
+fn dump(
+    store: &Store,
+    year: u16,
+    day: u8,
+    input: Option<PathBuf>,
+    stage: dump::Stage,
+    format: dump::Format,
+    part: Option<u8>,
+    params: Params,
+) -> ExitCode {
+    let Some(dump) = registry::find_dump(year, day) else {
+        eprintln!("No solution registered for {} day {}", year, day);
+        return ExitCode::FAILURE;
+    };
+    let parts: Vec<&registry::Entry> = registry::ENTRIES
+        .iter()
+        .filter(|e| e.year == year && e.day == day && part.is_none_or(|part| e.part == part))
+        .collect();
+    if parts.is_empty() {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let input = match load_input(store, year, day, input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match dump::dump(dump, &parts, &input, stage, &params) {
+        Ok(value) => {
+            println!("{}", dump::write(&value, format));
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Invalid input or parameters: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn bench(store: &Store, year: u16, day: Option<u8>, runs: usize, root: PathBuf) -> ExitCode {
+    let mut table = Table::new(&["day", "part", "parse (min / median / max)", "solve (min / median / max)", "answer"]);
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+
+    for entry in registry::ENTRIES
+        .iter()
+        .filter(|e| e.year == year && day.is_none_or(|d| e.day == d))
+    {
+        let (day, part) = (entry.day.to_string(), entry.part.to_string());
+
+        let Some(path) = find_input(store, &root, entry.year, entry.day, entry.part) else {
+            table.push(&[day.as_str(), &part, "", "", "no input"]);
+            continue;
+        };
+
+        let input = match read_input(Some(&path)) {
+            Ok(input) => input,
+            Err(e) => {
+                failed = true;
+                table.push(&[day, part, String::new(), String::new(), format!("could not read input: {}", e)]);
+                continue;
+            }
+        };
+
+        match entry.span().in_scope(|| timing::bench(entry.solution, &input, &Params::new(), runs)) {
+            Ok(benchmark) => {
+                total += benchmark.total();
+                table.push(&[
+                    day,
+                    part,
+                    benchmark.parse.to_string(),
+                    benchmark.solve.to_string(),
+                    benchmark.answer.to_string(),
+                ]);
+            },
+            Err(e) => {
+                failed = true;
+                table.push(&[day, part, String::new(), String::new(), format!("could not parse input: {}", e)]);
+            },
+        }
+    }
+
+    if table.is_empty() {
+        eprintln!("No solution registered for {}", year);
+        return ExitCode::FAILURE;
+    }
+
+    print!("{}", table);
+    println!();
+    println!("total of the medians: {:.2?}", total);
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn batch(store: &Store, store_root: &Path, year: u16, day: u8, part: Option<u8>, inputs: &[PathBuf], accounts: bool) -> ExitCode {
+    let entries: Vec<&registry::Entry> = registry::ENTRIES
+        .iter()
+        .filter(|e| e.year == year && e.day == day && part.is_none_or(|part| e.part == part))
+        .collect();
+    if entries.is_empty() {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let mut sources: Vec<batch::Source> = vec![];
+    for pattern in inputs {
+        match batch::Source::files(pattern, store) {
+            Ok(files) if files.is_empty() => eprintln!("warning: no input matches {}", pattern.display()),
+            Ok(files) => sources.extend(files),
+            Err(e) => {
+                eprintln!("Could not list the inputs of {}: {}", pattern.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if accounts {
+        match batch::Source::accounts(store_root, year, day) {
+            Ok(stored) => sources.extend(stored),
+            Err(e) => {
+                eprintln!("Could not list the accounts of the store: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if sources.is_empty() {
+        eprintln!("No input to run {} day {} against", year, day);
+        return ExitCode::FAILURE;
+    }
+
+    let rows = match batch::run_all(&entries, &sources) {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("Could not open the ledger: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut header = vec!["input".to_string()];
+    for entry in &entries {
+        header.extend([format!("part {}", entry.part), "time".to_string()]);
+    }
+    let mut table = Table::new(&header);
+    let mut failed = 0;
+
+    for row in &rows {
+        let mut cells = vec![row.source.clone()];
+        for report in &row.reports {
+            // The answer, or why there is none, or what is wrong with it
+            let answer = match (&report.answer, &report.status) {
+                (Some(answer), calendar::Status::Ok) => answer.to_string(),
+                (Some(answer), status) => format!("{} ({})", answer, status),
+                (None, status) => status.to_string(),
+            };
+            cells.extend([answer, report.elapsed.map(|elapsed| format!("{:.2?}", elapsed)).unwrap_or_default()]);
+        }
+        failed += row.reports.iter().filter(|report| report.status.is_failure()).count();
+
+        table.push(&cells);
+    }
+
+    print!("{}", table);
+    println!();
+    println!("{} inputs, {} failed", rows.len(), failed);
+
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn generate(year: u16, day: u8, size: usize, seed: Option<u64>, out: Option<PathBuf>, puzzle_like: bool) -> ExitCode {
+    let Some(generator) = find_generator(year, day, puzzle_like) else {
+        return ExitCode::FAILURE;
+    };
+
+    let seed = seed.unwrap_or_else(rand::random);
+    let generated = generator(&mut StdRng::seed_from_u64(seed), size);
+
+    let written = match out {
+        Some(path) => fs::write(path, &generated.input),
+        None => io::stdout().lock().write_all(generated.input.as_bytes()),
+    };
+    if let Err(e) = written {
+        eprintln!("Could not write the input: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    eprintln!("seed: {}", seed);
+    for part in [1, 2] {
+        if let Some(answer) = generated.answer(part) {
+            eprintln!("part {}: {}", part, answer);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// The generator of plain inputs of the day, or of inputs like the puzzle's own
+fn find_generator(year: u16, day: u8, puzzle_like: bool) -> Option<fn(&mut StdRng, usize) -> Generated> {
+    let Some(generator) = registry::find_generator(year, day) else {
+        eprintln!("No generator registered for {} day {}", year, day);
+        return None;
+    };
+    if !puzzle_like {
+        return Some(generator.input);
+    }
+
+    if generator.puzzle_like.is_none() {
+        eprintln!("{} day {} solves any input following the puzzle statement, it has no puzzle-like generator", year, day);
+    }
+    generator.puzzle_like
+}
+
+fn scale(year: u16, day: u8, part: u8, sizes: &[usize], seed: u64, runs: usize, puzzle_like: bool) -> ExitCode {
+    let Some(entry) = registry::find(year, day, part) else {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part);
+        return ExitCode::FAILURE;
+    };
+    let Some(generator) = find_generator(year, day, puzzle_like) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut table = Table::new(&["size", "bytes", "parse (min / median / max)", "solve (min / median / max)", "answer"]);
+    let mut failed = false;
+
+    for &size in sizes {
+        let generated = generator(&mut StdRng::seed_from_u64(seed), size);
+        let (size, bytes) = (size.to_string(), generated.input.len().to_string());
+
+        match entry.span().in_scope(|| timing::bench(entry.solution, &generated.input, &Params::new(), runs)) {
+            Ok(benchmark) => {
+                let answer = match generated.answer(part) {
+                    Some(expected) if *expected != benchmark.answer => {
+                        failed = true;
+                        format!("{}, expected {}", benchmark.answer, expected)
+                    },
+                    _ => benchmark.answer.to_string(),
+                };
+                table.push(&[size, bytes, benchmark.parse.to_string(), benchmark.solve.to_string(), answer]);
+            },
+            Err(e) => {
+                failed = true;
+                table.push(&[size, bytes, String::new(), String::new(), format!("could not parse input: {}", e)]);
+            },
+        }
+    }
+
+    print!("{}", table);
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn test(year: Option<u16>, day: Option<u8>, root: PathBuf) -> ExitCode {
+    let fixtures = match fixtures::discover(&root) {
+        Ok(fixtures) => fixtures,
+        Err(e) => {
+            eprintln!("Could not load fixtures: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut ignored) = (0, 0, 0);
+
+    for fixture in fixtures
+        .iter()
+        .filter(|f| year.is_none_or(|y| f.year == y) && day.is_none_or(|d| f.day == d))
+    {
+        match fixture.run() {
+            Ok(Outcome::Passed) => {
+                passed += 1;
+                println!("{} ... ok", fixture);
+            },
+            Ok(Outcome::Ignored(reason)) => {
+                ignored += 1;
+                println!("{} ... ignored, {}", fixture, reason);
+            },
+            Ok(Outcome::Failed(answer)) => {
+                failed += 1;
+                println!("{} ... FAILED, expected {} but got {}", fixture, fixture.answer, answer);
+            },
+            Ok(Outcome::Unparsed(e)) => {
+                failed += 1;
+                println!("{} ... FAILED, could not parse the input: {}", fixture, e);
+            },
+            Ok(Outcome::Panicked) => {
+                failed += 1;
+                println!("{} ... FAILED, the solution panicked", fixture);
+            },
+            Ok(Outcome::Unregistered) => {
+                failed += 1;
+                println!("{} ... FAILED, no solution is registered", fixture);
+            },
+            Err(e) => {
+                failed += 1;
+                println!("{} ... FAILED, {}", fixture, e);
+            },
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} ignored", passed, failed, ignored);
+
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn input_add(
+    store: &Store,
+    year: u16,
+    day: u8,
+    input: Option<PathBuf>,
+    fetch: bool,
+    session: Option<String>,
+    url: String,
+) -> ExitCode {
+    let stored = if fetch {
+        let Some(session) = session else {
+            eprintln!("Fetching an input needs the session cookie of the account, see `--session`");
+            return ExitCode::FAILURE;
+        };
+
+        store.fetch(&HttpFetcher::new(session).with_base_url(url), year, day)
+    } else {
+        read_input(input.as_deref()).and_then(|input| store.add(year, day, &input))
+    };
+
+    match stored {
+        Ok(path) => {
+            println!("{}", path.display());
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Could not store the input: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn input_list(store: &Store) -> ExitCode {
+    let inputs = match store.list() {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Could not list {}: {}", store.dir().display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut table = Table::new(&["year", "day", "path"]);
+    for input in inputs {
+        table.push(&[input.year.to_string(), input.day.to_string(), input.path.display().to_string()]);
+    }
+
+    if table.is_empty() {
+        println!("No input stored in {}", store.dir().display());
+    } else {
+        print!("{}", table);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn input_verify(store: &Store) -> ExitCode {
+    let report = match store.verify() {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Could not verify {}: {}", store.dir().display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = 0;
+
+    for (input, integrity) in &report {
+        if *integrity != Integrity::Intact {
+            failed += 1;
+        }
+        println!("{} day {:02} ... {}", input.year, input.day, integrity);
+    }
+
+    println!();
+    println!("{} intact, {} failed", report.len() - failed, failed);
+
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn record_answer(
+    store: &Store,
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<PathBuf>,
+    record: impl FnOnce(&mut Ledger, &str),
+) -> ExitCode {
+    if registry::find(year, day, part).is_none() {
+        eprintln!("No solution registered for {} day {} part {}", year, day, part);
+        return ExitCode::FAILURE;
+    }
+
+    let input = match load_input(store, year, day, input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let saved = Ledger::open(store, year).and_then(|mut ledger| {
+        record(&mut ledger, &input);
+        ledger.save()
+    });
+
+    match saved {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Could not update the ledger: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(year: u16, day: u8, page: Option<PathBuf>, root: PathBuf) -> ExitCode {
+    match scaffold::new_day(&root, year, day) {
+        Ok(written) => written.iter().for_each(|path| println!("{}", path.display())),
+        Err(e) => {
+            eprintln!("Could not create {} day {}: {}", year, day, e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match page {
+        Some(page) => readme(year, day, &page, &root),
+        None => ExitCode::SUCCESS,
+    }
+}
+
+fn readme(year: u16, day: u8, page: &Path, root: &Path) -> ExitCode {
+    let written = fs::read_to_string(page).and_then(|html| scaffold::write_readmes(root, year, day, &html));
+
+    match written {
+        Ok(written) if written.is_empty() => {
+            eprintln!("No puzzle description found in {}", page.display());
+            ExitCode::FAILURE
+        },
+        Ok(written) => {
+            written.iter().for_each(|path| println!("{}", path.display()));
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Could not write the README.md of {} day {}: {}", year, day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
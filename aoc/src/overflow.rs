@@ -0,0 +1,82 @@
+use std::{fmt, panic::{self, AssertUnwindSafe, PanicHookInfo}, sync::{atomic::{AtomicUsize, Ordering}, Mutex, Once}};
+
+// Built with overflow checks, arithmetic panics on the first operation overflowing rather than
+// wrapping around. Debug builds have them, as does the `checked` profile for optimised runs:
+// `cargo run --profile checked -- run 2023 11 2`.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    // What overflowed: `add`, `multiply`, `negate`, ...
+    pub operation: String,
+    // Where, as `<file>:<line>:<column>`, when the panic hook saw it
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "attempt to {} with overflow", self.operation)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Runs `f`, turning an overflow in it into an error. Any other panic goes on unwinding.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Overflow> {
+    install_hook();
+
+    CATCHING.fetch_add(1, Ordering::SeqCst);
+    let caught = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.fetch_sub(1, Ordering::SeqCst);
+
+    let payload = match caught {
+        Ok(value) => return Ok(value),
+        Err(payload) => payload,
+    };
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+
+    match message.and_then(operation) {
+        Some(operation) => {
+            let location = LAST.lock().unwrap_or_else(|e| e.into_inner()).take().and_then(|last| {
+                (last.operation == operation).then_some(last.location).flatten()
+            });
+
+            Err(Overflow { operation: operation.to_string(), location })
+        },
+        None => panic::resume_unwind(payload),
+    }
+}
+
+// How many `catch` are running, the hook only keeps quiet about the overflows they report
+static CATCHING: AtomicUsize = AtomicUsize::new(0);
+// The last overflow the hook saw. The panic may reach `catch` on another thread than the one it
+// happened on (from a rayon pool), so this cannot be a thread local: with parts running
+// concurrently, an overflow may be told the location of another one of the same operation.
+static LAST: Mutex<Option<Overflow>> = Mutex::new(None);
+
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+            match info.payload_as_str().and_then(operation) {
+                Some(operation) if CATCHING.load(Ordering::SeqCst) > 0 => {
+                    let overflow = Overflow { operation: operation.to_string(), location: info.location().map(ToString::to_string) };
+                    *LAST.lock().unwrap_or_else(|e| e.into_inner()) = Some(overflow);
+                },
+                _ => previous(info),
+            }
+        }));
+    });
+}
+
+// The operation of the message of an overflow panic, `attempt to add with overflow`
+fn operation(message: &str) -> Option<&str> {
+    message.strip_prefix("attempt to ")?.strip_suffix(" with overflow")
+}
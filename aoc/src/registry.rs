@@ -0,0 +1,308 @@
+use rand::rngs::StdRng;
+use aoc_core::{Frame, Generated, ParseError, Solution, Stages, Tuned};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solution: &'static dyn Solution,
+}
+
+impl Entry {
+    // Holds the `parse` and `solve` spans of a run, see `logging`
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!("part", year = self.year, day = self.day, part = self.part)
+    }
+}
+
+// Both parts of a day share the `parse` of its crate, `tuned` marks the parts that also take the
+// puzzle settings, see `aoc_core::Params`
+macro_rules! register {
+    ($($year:literal, $day:literal, $part:literal => $krate:ident::$solve:ident $($tuned:ident)?),* $(,)?) => {
+        pub static ENTRIES: &[Entry] = &[
+            $(Entry { year: $year, day: $day, part: $part, solution: register!(@solution $krate $solve $($tuned)?) }),*
+        ];
+    };
+    (@solution $krate:ident $solve:ident) => {
+        &Stages::new($krate::parse, |parsed| $krate::$solve(parsed))
+    };
+    (@solution $krate:ident $solve:ident tuned) => {
+        &Tuned::new($krate::parse, |parsed, config| $krate::$solve(parsed, config))
+    };
+}
+
+register! {
+    2023, 1, 1 => aoc_2023_day_01::part1,
+    2023, 1, 2 => aoc_2023_day_01::part2,
+    2023, 2, 1 => aoc_2023_day_02::part1 tuned,
+    2023, 2, 2 => aoc_2023_day_02::part2,
+    2023, 3, 1 => aoc_2023_day_03::part1,
+    2023, 3, 2 => aoc_2023_day_03::part2,
+    2023, 4, 1 => aoc_2023_day_04::part1,
+    2023, 4, 2 => aoc_2023_day_04::part2,
+    2023, 5, 1 => aoc_2023_day_05::part1,
+    2023, 5, 2 => aoc_2023_day_05::part2,
+    2023, 6, 1 => aoc_2023_day_06::part1,
+    2023, 6, 2 => aoc_2023_day_06::part2,
+    2023, 7, 1 => aoc_2023_day_07::part1,
+    2023, 7, 2 => aoc_2023_day_07::part2,
+    2023, 8, 1 => aoc_2023_day_08::part1,
+    2023, 8, 2 => aoc_2023_day_08::part2,
+    2023, 9, 1 => aoc_2023_day_09::part1,
+    2023, 9, 2 => aoc_2023_day_09::part2,
+    2023, 10, 1 => aoc_2023_day_10::part1,
+    2023, 10, 2 => aoc_2023_day_10::part2,
+    2023, 11, 1 => aoc_2023_day_11::part1,
+    2023, 11, 2 => aoc_2023_day_11::part2,
+    2023, 12, 1 => aoc_2023_day_12::part1,
+    2023, 12, 2 => aoc_2023_day_12::part2,
+    2023, 13, 1 => aoc_2023_day_13::part1,
+    2023, 13, 2 => aoc_2023_day_13::part2,
+    2023, 14, 1 => aoc_2023_day_14::part1,
+    2023, 14, 2 => aoc_2023_day_14::part2 tuned,
+    2023, 15, 1 => aoc_2023_day_15::part1,
+    2023, 15, 2 => aoc_2023_day_15::part2,
+    2023, 16, 1 => aoc_2023_day_16::part1,
+    2023, 16, 2 => aoc_2023_day_16::part2,
+    2023, 17, 1 => aoc_2023_day_17::part1 tuned,
+    2023, 17, 2 => aoc_2023_day_17::part2 tuned,
+    2023, 18, 1 => aoc_2023_day_18::part1,
+    2023, 18, 2 => aoc_2023_day_18::part2,
+    2023, 19, 1 => aoc_2023_day_19::part1,
+    2023, 19, 2 => aoc_2023_day_19::part2,
+    2023, 20, 1 => aoc_2023_day_20::part1,
+    2023, 20, 2 => aoc_2023_day_20::part2,
+    2023, 21, 1 => aoc_2023_day_21::part1 tuned,
+    2023, 21, 2 => aoc_2023_day_21::part2 tuned,
+    2023, 22, 1 => aoc_2023_day_22::part1,
+    2023, 22, 2 => aoc_2023_day_22::part2,
+    2023, 23, 1 => aoc_2023_day_23::part1,
+    2023, 23, 2 => aoc_2023_day_23::part2,
+    2023, 24, 1 => aoc_2023_day_24::part1 tuned,
+    2023, 24, 2 => aoc_2023_day_24::part2 tuned,
+    2023, 25, 1 => aoc_2023_day_25::part1,
+}
+
+// Days that can draw their puzzle, see `aoc_core::visual`
+pub struct Visual {
+    pub year: u16,
+    pub day: u8,
+    pub frames: fn(&str, u8) -> Result<Vec<Frame>, ParseError>,
+}
+
+macro_rules! visualise {
+    ($($year:literal, $day:literal => $krate:ident),* $(,)?) => {
+        pub static VISUALS: &[Visual] = &[
+            $(Visual { year: $year, day: $day, frames: |input, part| Ok($krate::frames(&$krate::parse(input)?, part)) }),*
+        ];
+    };
+}
+
+visualise! {
+    2023, 10 => aoc_2023_day_10,
+    2023, 14 => aoc_2023_day_14,
+    2023, 17 => aoc_2023_day_17,
+    2023, 18 => aoc_2023_day_18,
+    2023, 23 => aoc_2023_day_23,
+}
+
+// Days that can make up random inputs of their puzzle at any size, see `aoc_core::generate`. The
+// inputs only follow the puzzle statement, the days whose solutions rely on more than that, like
+// the puzzle's own inputs do, can make up inputs like those as well.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    pub input: fn(&mut StdRng, usize) -> Generated,
+    pub puzzle_like: Option<fn(&mut StdRng, usize) -> Generated>,
+}
+
+macro_rules! generate {
+    (@puzzle_like $krate:ident) => {
+        None
+    };
+    (@puzzle_like $krate:ident puzzle_like) => {
+        Some(|rng, size| $krate::generate::puzzle_like(rng, size))
+    };
+    ($($year:literal, $day:literal => $krate:ident $(+ $puzzle_like:ident)?),* $(,)?) => {
+        pub static GENERATORS: &[Generator] = &[
+            $(Generator {
+                year: $year,
+                day: $day,
+                input: |rng, size| $krate::generate::input(rng, size),
+                puzzle_like: generate!(@puzzle_like $krate $($puzzle_like)?),
+            }),*
+        ];
+    };
+}
+
+generate! {
+    2023, 1 => aoc_2023_day_01,
+    2023, 2 => aoc_2023_day_02,
+    2023, 3 => aoc_2023_day_03,
+    2023, 4 => aoc_2023_day_04,
+    2023, 5 => aoc_2023_day_05,
+    2023, 6 => aoc_2023_day_06 + puzzle_like,
+    2023, 7 => aoc_2023_day_07,
+    2023, 8 => aoc_2023_day_08,
+    2023, 9 => aoc_2023_day_09,
+    2023, 10 => aoc_2023_day_10,
+    2023, 11 => aoc_2023_day_11,
+    2023, 12 => aoc_2023_day_12,
+    2023, 13 => aoc_2023_day_13,
+    2023, 14 => aoc_2023_day_14,
+    2023, 15 => aoc_2023_day_15,
+    2023, 16 => aoc_2023_day_16,
+    2023, 17 => aoc_2023_day_17,
+    2023, 18 => aoc_2023_day_18,
+    2023, 19 => aoc_2023_day_19,
+    2023, 20 => aoc_2023_day_20,
+    2023, 21 => aoc_2023_day_21 + puzzle_like,
+    2023, 22 => aoc_2023_day_22,
+    2023, 23 => aoc_2023_day_23,
+    2023, 24 => aoc_2023_day_24 + puzzle_like,
+    2023, 25 => aoc_2023_day_25,
+}
+
+// The parsing of every day on its own: `parse` only says whether the input is valid, `round_trip`
+// writes the model back as puzzle text and checks that it parses the same, see
+// `aoc_core::parse::round_trip`
+pub struct Parser {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str) -> Result<(), ParseError>,
+    pub round_trip: fn(&str) -> Result<(), String>,
+}
+
+macro_rules! parsers {
+    ($($year:literal, $day:literal => $krate:ident),* $(,)?) => {
+        pub static PARSERS: &[Parser] = &[
+            $(Parser {
+                year: $year,
+                day: $day,
+                parse: |input| $krate::parse(input).map(drop),
+                round_trip: |input| aoc_core::parse::round_trip(input, $krate::parse, |parsed| $krate::render(parsed)),
+            }),*
+        ];
+    };
+}
+
+parsers! {
+    2023, 1 => aoc_2023_day_01,
+    2023, 2 => aoc_2023_day_02,
+    2023, 3 => aoc_2023_day_03,
+    2023, 4 => aoc_2023_day_04,
+    2023, 5 => aoc_2023_day_05,
+    2023, 6 => aoc_2023_day_06,
+    2023, 7 => aoc_2023_day_07,
+    2023, 8 => aoc_2023_day_08,
+    2023, 9 => aoc_2023_day_09,
+    2023, 10 => aoc_2023_day_10,
+    2023, 11 => aoc_2023_day_11,
+    2023, 12 => aoc_2023_day_12,
+    2023, 13 => aoc_2023_day_13,
+    2023, 14 => aoc_2023_day_14,
+    2023, 15 => aoc_2023_day_15,
+    2023, 16 => aoc_2023_day_16,
+    2023, 17 => aoc_2023_day_17,
+    2023, 18 => aoc_2023_day_18,
+    2023, 19 => aoc_2023_day_19,
+    2023, 20 => aoc_2023_day_20,
+    2023, 21 => aoc_2023_day_21,
+    2023, 22 => aoc_2023_day_22,
+    2023, 23 => aoc_2023_day_23,
+    2023, 24 => aoc_2023_day_24,
+    2023, 25 => aoc_2023_day_25,
+}
+
+// The models of every day as JSON, see `dump`: `parsed` is what `parse` returns and `solved`, for
+// the days marked with it, the state they build on the way to their answers
+#[cfg(feature = "dump")]
+pub type ToJson = fn(&str) -> Result<serde_json::Value, String>;
+
+#[cfg(feature = "dump")]
+pub struct Dump {
+    pub year: u16,
+    pub day: u8,
+    pub parsed: ToJson,
+    pub solved: Option<ToJson>,
+}
+
+#[cfg(feature = "dump")]
+macro_rules! dumps {
+    ($($year:literal, $day:literal => $krate:ident $($solved:ident)?),* $(,)?) => {
+        pub static DUMPS: &[Dump] = &[
+            $(Dump {
+                year: $year,
+                day: $day,
+                parsed: |input| crate::dump::to_json(input, $krate::parse, |parsed| serde_json::to_value(parsed)),
+                solved: dumps!(@solved $krate $($solved)?),
+            }),*
+        ];
+    };
+    (@solved $krate:ident) => {
+        None
+    };
+    (@solved $krate:ident solved) => {
+        Some(|input| crate::dump::to_json(input, $krate::parse, |parsed| serde_json::to_value($krate::solved(parsed))))
+    };
+}
+
+#[cfg(feature = "dump")]
+dumps! {
+    2023, 1 => aoc_2023_day_01,
+    2023, 2 => aoc_2023_day_02,
+    2023, 3 => aoc_2023_day_03,
+    2023, 4 => aoc_2023_day_04,
+    2023, 5 => aoc_2023_day_05,
+    2023, 6 => aoc_2023_day_06,
+    2023, 7 => aoc_2023_day_07,
+    2023, 8 => aoc_2023_day_08 solved,
+    2023, 9 => aoc_2023_day_09,
+    2023, 10 => aoc_2023_day_10,
+    2023, 11 => aoc_2023_day_11,
+    2023, 12 => aoc_2023_day_12,
+    2023, 13 => aoc_2023_day_13,
+    2023, 14 => aoc_2023_day_14,
+    2023, 15 => aoc_2023_day_15,
+    2023, 16 => aoc_2023_day_16,
+    2023, 17 => aoc_2023_day_17,
+    2023, 18 => aoc_2023_day_18,
+    2023, 19 => aoc_2023_day_19 solved,
+    2023, 20 => aoc_2023_day_20,
+    2023, 21 => aoc_2023_day_21,
+    2023, 22 => aoc_2023_day_22 solved,
+    2023, 23 => aoc_2023_day_23,
+    2023, 24 => aoc_2023_day_24,
+    2023, 25 => aoc_2023_day_25,
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Entry> {
+    ENTRIES
+        .iter()
+        .find(|e| e.year == year && e.day == day && e.part == part)
+}
+
+pub fn find_visual(year: u16, day: u8) -> Option<&'static Visual> {
+    VISUALS
+        .iter()
+        .find(|v| v.year == year && v.day == day)
+}
+
+pub fn find_generator(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|g| g.year == year && g.day == day)
+}
+
+pub fn find_parser(year: u16, day: u8) -> Option<&'static Parser> {
+    PARSERS
+        .iter()
+        .find(|p| p.year == year && p.day == day)
+}
+
+#[cfg(feature = "dump")]
+pub fn find_dump(year: u16, day: u8) -> Option<&'static Dump> {
+    DUMPS
+        .iter()
+        .find(|d| d.year == year && d.day == day)
+}
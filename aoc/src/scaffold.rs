@@ -0,0 +1,311 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+const GENERATE_TEMPLATE: &str = include_str!("../templates/day/generate.rs.tmpl");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("../templates/day/fuzz_target.rs.tmpl");
+const FIXTURES_TEMPLATE: &str = include_str!("../templates/day/fixtures.toml.tmpl");
+
+// New day
+// -----------------------------------------------------------------------------
+
+// Creates the crate of a day in the workspace at `root`, registers both of its parts, its generator,
+// its parser and its dump in the runner and gives it a fuzz target, returns the files written
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let day_dir = root.join(year.to_string()).join(format!("{:02}", day));
+    if day_dir.join("Cargo.toml").exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", day_dir.display())));
+    }
+
+    // Every edit is checked before anything is written, a day already registered leaves no trace
+    let registrations = [
+        (root.join("Cargo.toml"), add_member as fn(&str, u16, u8) -> io::Result<String>),
+        (root.join("aoc").join("Cargo.toml"), add_runner_dependency),
+        (root.join("aoc").join("src").join("registry.rs"), add_entries),
+        (root.join("fuzz").join("Cargo.toml"), add_fuzz_target),
+    ]
+    .into_iter()
+    .map(|(path, register)| Ok((register(&fs::read_to_string(&path)?, year, day)?, path)))
+    .collect::<io::Result<Vec<(String, PathBuf)>>>()?;
+
+    let mut written: Vec<PathBuf> = vec![];
+
+    let mut write = |path: PathBuf, contents: &str| -> io::Result<()> {
+        fs::create_dir_all(path.parent().expect("scaffolded files live in a directory"))?;
+        fs::write(&path, contents)?;
+        written.push(path);
+        Ok(())
+    };
+
+    write(day_dir.join("Cargo.toml"), &fill(CARGO_TEMPLATE, year, day))?;
+    write(day_dir.join("src").join("lib.rs"), &fill(LIB_TEMPLATE, year, day))?;
+    write(day_dir.join("src").join("generate.rs"), &fill(GENERATE_TEMPLATE, year, day))?;
+    for part in 1..=2 {
+        let puzzle_dir = day_dir.join(format!("puzzle-{}", part));
+        write(puzzle_dir.join(crate::fixtures::SIDECAR), &fill(FIXTURES_TEMPLATE, year, day))?;
+        write(puzzle_dir.join("test.txt"), "")?;
+    }
+
+    write(
+        root.join("fuzz").join("fuzz_targets").join(format!("parse_{}_{:02}.rs", year, day)),
+        &fill(FUZZ_TARGET_TEMPLATE, year, day),
+    )?;
+
+    for (contents, path) in registrations {
+        write(path, &contents)?;
+    }
+
+    Ok(written)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", day))
+}
+
+fn malformed(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("could not find where to register the day in {}", what))
+}
+
+// The workspace picks up every day of a year through a `"<year>/*"` member
+fn add_member(manifest: &str, year: u16, _day: u8) -> io::Result<String> {
+    let member = format!("\"{}/*\",", year);
+    if manifest.lines().any(|line| line.trim() == member) {
+        return Ok(manifest.to_string());
+    }
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|line| line.trim() == "members = [").ok_or_else(|| malformed("Cargo.toml"))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]").ok_or_else(|| malformed("Cargo.toml"))?;
+
+    let line = format!("    {}", member);
+    lines.insert(end, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_dependency(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let name = format!("aoc-{}-day-{:02}", year, day);
+    let dependency = format!("{} = {{ path = \"../{}/{:02}\" }}", name, year, day);
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let days: Vec<usize> = (0..lines.len()).filter(|i| lines[*i].starts_with("aoc-") && lines[*i].contains("path = ")).collect();
+    let last = *days.last().ok_or_else(|| malformed("aoc/Cargo.toml"))?;
+    let index = days.into_iter().find(|i| lines[*i] > name.as_str()).unwrap_or(last + 1);
+
+    lines.insert(index, &dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// The runner also turns on the `serde` feature of the day for `aoc dump`
+fn add_runner_dependency(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let manifest = add_dependency(manifest, year, day)?;
+    let feature = format!("\"aoc-{}-day-{:02}/serde\",", year, day);
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|line| line.trim() == "dump = [").ok_or_else(|| malformed("aoc/Cargo.toml"))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]").ok_or_else(|| malformed("aoc/Cargo.toml"))?;
+    let index = (start + 1..end)
+        .find(|i| lines[*i].contains("-day-") && lines[*i].trim() > feature.as_str())
+        .unwrap_or(end);
+
+    let line = format!("    {}", feature);
+    lines.insert(index, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Entries are kept in order, `<year>, <day>, <part> => <crate>::<part>`
+fn add_entries(registry: &str, year: u16, day: u8) -> io::Result<String> {
+    let key = |line: &str| -> Option<(u16, u8)> {
+        let (key, _) = line.split_once("=>")?;
+        let mut numbers = key.split(',').map(|n| n.trim().parse::<u32>());
+
+        match (numbers.next(), numbers.next(), numbers.next(), numbers.next()) {
+            (Some(Ok(year)), Some(Ok(day)), Some(Ok(_)), None) => Some((year as u16, day as u8)),
+            _ => None,
+        }
+    };
+
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|i| key(&lines[*i]).is_some()).collect();
+    if entries.iter().any(|i| key(&lines[*i]) == Some((year, day))) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} day {} is already registered", year, day)));
+    }
+
+    let last = *entries.last().ok_or_else(|| malformed("aoc/src/registry.rs"))?;
+    let index = entries.into_iter().find(|i| key(&lines[*i]) > Some((year, day))).unwrap_or(last + 1);
+
+    for part in (1..=2).rev() {
+        lines.insert(index, format!("    {}, {}, {} => aoc_{}_day_{:02}::part{},", year, day, part, year, day, part));
+    }
+
+    for list in ["generate! {", "parsers! {", "dumps! {"] {
+        add_day(&mut lines, list, year, day)?;
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Generators, parsers and dumps are kept in order too, `<year>, <day> => <crate>` within `<list> { ... }`
+fn add_day(lines: &mut Vec<String>, list: &str, year: u16, day: u8) -> io::Result<()> {
+    let key = |line: &str| -> Option<(u16, u8)> {
+        let (key, _) = line.split_once("=>")?;
+        let (year, day) = key.split_once(',')?;
+
+        Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+    };
+
+    let start = lines.iter().position(|line| line.trim() == list).ok_or_else(|| malformed("aoc/src/registry.rs"))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "}").ok_or_else(|| malformed("aoc/src/registry.rs"))?;
+    let index = (start + 1..end).find(|i| key(&lines[*i]) > Some((year, day))).unwrap_or(end);
+
+    lines.insert(index, format!("    {}, {} => aoc_{}_day_{:02},", year, day, year, day));
+
+    Ok(())
+}
+
+// The fuzz crate depends on every day like the runner does, with a `parse_<year>_<day>` target each
+fn add_fuzz_target(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let manifest = add_dependency(manifest, year, day)?;
+    let name = format!("parse_{}_{:02}", year, day);
+
+    Ok(format!(
+        "{}\n[[bin]]\nname = \"{}\"\npath = \"fuzz_targets/{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        manifest, name, name
+    ))
+}
+
+// Puzzle page
+// -----------------------------------------------------------------------------
+
+// Writes the README.md of every part found in the page, returns the files written
+pub fn write_readmes(root: &Path, year: u16, day: u8, html: &str) -> io::Result<Vec<PathBuf>> {
+    let day_dir = root.join(year.to_string()).join(format!("{:02}", day));
+    let mut written: Vec<PathBuf> = vec![];
+
+    for (part, readme) in readmes_from_html(html).into_iter().enumerate() {
+        let puzzle_dir = day_dir.join(format!("puzzle-{}", part + 1));
+        fs::create_dir_all(&puzzle_dir)?;
+        fs::write(puzzle_dir.join("README.md"), readme)?;
+        written.push(puzzle_dir.join("README.md"));
+    }
+
+    Ok(written)
+}
+
+// The text of the `<article class="day-desc">` of a saved puzzle page, one per part solved so far,
+// laid out like the README.md of the puzzle directories: the title, the paragraphs separated by a
+// blank line and the examples as they are
+pub fn readmes_from_html(html: &str) -> Vec<String> {
+    let mut readmes: Vec<String> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        rest = &rest[start..];
+        let end = rest.find("</article>").unwrap_or(rest.len());
+
+        readmes.push(article_text(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    readmes
+}
+
+fn article_text(article: &str) -> String {
+    let mut text = String::new();
+    let mut block: Option<String> = None;
+    let mut rest = article;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut text, block.as_deref(), rest);
+            break;
+        };
+        push_text(&mut text, block.as_deref(), &rest[..tag_start]);
+
+        let tag_end = rest[tag_start..].find('>').map_or(rest.len(), |end| tag_start + end);
+        let tag = rest[tag_start + 1..tag_end].trim();
+        rest = rest.get(tag_end + 1..).unwrap_or_default();
+
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2" | "p" | "pre" | "li", false) => block = Some(name),
+            ("h2" | "li", true) => {
+                text.push('\n');
+                block = None;
+            },
+            ("p", true) => {
+                text.push_str("\n\n");
+                block = None;
+            },
+            ("pre", true) => {
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                block = None;
+            },
+            _ => {},
+        }
+    }
+
+    text.trim_end().to_string() + "\n"
+}
+
+// Only the examples keep their line breaks, the markup between blocks is dropped
+fn push_text(text: &mut String, block: Option<&str>, raw: &str) {
+    match block {
+        None => {},
+        Some("pre") => text.push_str(&decode_entities(raw)),
+        Some(_) => text.push_str(&decode_entities(&raw.replace('\n', " "))),
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]).filter(|entity| entity.len() <= 8);
+        let character = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
@@ -0,0 +1,282 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::{Path, PathBuf}};
+use sha2::{Digest, Sha256};
+
+// Every year of the store lists the checksums of its inputs in the format of `sha256sum`, so that
+// `sha256sum -c SHA256SUMS` can check them as well
+pub const MANIFEST: &str = "SHA256SUMS";
+
+// Store
+// -----------------------------------------------------------------------------
+
+// Puzzle inputs kept outside of the repository, laid out as `<root>/[<account>/]<year>/<DD>.txt`
+// since every account gets its own inputs
+#[derive(Debug, Clone)]
+pub struct Store {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredInput {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    Intact,
+    Modified { expected: String, found: String },
+    Missing,
+    Untracked,
+}
+
+impl Store {
+    // `$XDG_DATA_HOME/aoc/inputs`, falling back to `~/.local/share/aoc/inputs`
+    pub fn default_root() -> PathBuf {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("share")))
+            .unwrap_or_default()
+            .join("aoc")
+            .join("inputs")
+    }
+
+    pub fn new(root: impl Into<PathBuf>, account: Option<&str>) -> Self {
+        let root = root.into();
+
+        match account {
+            Some(account) => Self { dir: root.join(account) },
+            None => Self { dir: root },
+        }
+    }
+
+    // The accounts holding inputs under `root`, the folders that are not years
+    pub fn accounts(root: &Path) -> io::Result<Vec<String>> {
+        let entries = match fs::read_dir(root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut accounts: Vec<String> = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|name| name.to_str());
+
+            if let (true, Some(name)) = (path.is_dir(), name) {
+                if name.parse::<u16>().is_err() {
+                    accounts.push(name.to_string());
+                }
+            }
+        }
+
+        accounts.sort();
+        Ok(accounts)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(file_name(day))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.path(year, day)) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Stores the input, replacing the previous one, and records its checksum in the manifest
+    pub fn add(&self, year: u16, day: u8, input: &str) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+        let year_dir = path.parent().expect("inputs live in a year directory");
+        fs::create_dir_all(year_dir)?;
+        fs::write(&path, input)?;
+
+        let mut manifest = read_manifest(year_dir)?;
+        manifest.insert(file_name(day), sha256(input.as_bytes()));
+        write_manifest(year_dir, &manifest)?;
+
+        Ok(path)
+    }
+
+    pub fn fetch(&self, fetcher: &dyn Fetcher, year: u16, day: u8) -> io::Result<PathBuf> {
+        let input = fetcher.fetch(year, day)?;
+
+        self.add(year, day, &input)
+    }
+
+    pub fn list(&self) -> io::Result<Vec<StoredInput>> {
+        let mut inputs: Vec<StoredInput> = vec![];
+
+        for (year, year_dir) in self.years()? {
+            for entry in fs::read_dir(&year_dir)? {
+                let path = entry?.path();
+
+                if let Some(day) = path.file_name().and_then(|name| name.to_str()).and_then(parse_file_name) {
+                    inputs.push(StoredInput { year, day, path });
+                }
+            }
+        }
+
+        inputs.sort_by_key(|input| (input.year, input.day));
+        Ok(inputs)
+    }
+
+    // Checks every input against the manifest of its year, the inputs only found on one side are
+    // reported too
+    pub fn verify(&self) -> io::Result<Vec<(StoredInput, Integrity)>> {
+        let mut report: Vec<(StoredInput, Integrity)> = vec![];
+        let inputs = self.list()?;
+
+        for (year, year_dir) in self.years()? {
+            let mut manifest = read_manifest(&year_dir)?;
+
+            for input in inputs.iter().filter(|input| input.year == year) {
+                let found = sha256(&fs::read(&input.path)?);
+
+                let integrity = match manifest.remove(&file_name(input.day)) {
+                    None => Integrity::Untracked,
+                    Some(expected) if expected == found => Integrity::Intact,
+                    Some(expected) => Integrity::Modified { expected, found },
+                };
+                report.push((input.clone(), integrity));
+            }
+
+            for name in manifest.into_keys() {
+                if let Some(day) = parse_file_name(&name) {
+                    let input = StoredInput { year, day, path: year_dir.join(name) };
+                    report.push((input, Integrity::Missing));
+                }
+            }
+        }
+
+        report.sort_by_key(|(input, _)| (input.year, input.day));
+        Ok(report)
+    }
+
+    fn years(&self) -> io::Result<Vec<(u16, PathBuf)>> {
+        let mut years: Vec<(u16, PathBuf)> = vec![];
+
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(years),
+            Err(e) => return Err(e),
+        };
+
+        // Account folders sit next to the years when no account is selected, they are not numbers
+        for entry in entries {
+            let path = entry?.path();
+            let year = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.parse::<u16>().ok());
+
+            if let (true, Some(year)) = (path.is_dir(), year) {
+                years.push((year, path));
+            }
+        }
+
+        Ok(years)
+    }
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Intact => write!(f, "ok"),
+            Self::Modified { expected, found } => write!(f, "modified, expected {} but found {}", expected, found),
+            Self::Missing => write!(f, "missing, listed in the manifest only"),
+            Self::Untracked => write!(f, "untracked, not listed in the manifest"),
+        }
+    }
+}
+
+fn file_name(day: u8) -> String {
+    format!("{:02}.txt", day)
+}
+
+fn parse_file_name(name: &str) -> Option<u8> {
+    name.strip_suffix(".txt").and_then(|day| day.parse().ok())
+}
+
+// Manifest
+// -----------------------------------------------------------------------------
+pub fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn read_manifest(year_dir: &Path) -> io::Result<BTreeMap<String, String>> {
+    let manifest = match fs::read_to_string(year_dir.join(MANIFEST)) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(e),
+    };
+
+    manifest
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            // `<checksum>  <file>`, the file may be flagged as binary with a `*`
+            line.split_once(' ')
+                .map(|(checksum, name)| (name.trim_start().trim_start_matches('*').to_string(), checksum.to_string()))
+                .ok_or_else(|| io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: expected `<checksum>  <file>`, found `{}`", year_dir.join(MANIFEST).display(), line)
+                ))
+        })
+        .collect()
+}
+
+fn write_manifest(year_dir: &Path, manifest: &BTreeMap<String, String>) -> io::Result<()> {
+    let contents: String = manifest
+        .iter()
+        .map(|(name, checksum)| format!("{}  {}\n", checksum, name))
+        .collect();
+
+    fs::write(year_dir.join(MANIFEST), contents)
+}
+
+// Fetching
+// -----------------------------------------------------------------------------
+
+// Where the inputs missing from the store come from
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String>;
+}
+
+// Downloads the inputs from `<base_url>/<year>/day/<day>/input` with the session cookie of an
+// account, the base URL can point at any server serving the same paths
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Self { base_url: Self::BASE_URL.to_string(), session: session.into() }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+            .call()
+            .map_err(|e| io::Error::other(format!("{}: {}", url, e)))?;
+
+        response.into_string()
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+// Plain text table, every column is as wide as its widest cell
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(header: &[S]) -> Self {
+        Self { header: header.iter().map(S::to_string).collect(), rows: vec![] }
+    }
+
+    pub fn push<S: ToString>(&mut self, row: &[S]) {
+        self.rows.push(row.iter().map(S::to_string).collect());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self.rows.iter().map(Vec::len).chain([self.header.len()]).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                [&self.header]
+                    .into_iter()
+                    .chain(&self.rows)
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+        for row in [&self.header, &separator].into_iter().chain(&self.rows) {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+
+            writeln!(f, "{}", line.join("  ").trim_end())?;
+        }
+
+        Ok(())
+    }
+}
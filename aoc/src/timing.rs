@@ -0,0 +1,79 @@
+use std::{fmt, time::Duration};
+use aoc_core::{Answer, Params, ParseError, Solution};
+
+// Stats
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = match samples.len() {
+            0 => return None,
+            n if n % 2 == 0 => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+
+        Some(Self { min: samples[0], median, max: samples[samples.len() - 1] })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "{:.2?}", self.median)
+        } else {
+            write!(f, "{:.2?} / {:.2?} / {:.2?}", self.min, self.median, self.max)
+        }
+    }
+}
+
+// Benchmark
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Benchmark {
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+// Runs the solution `runs` times (at least once) on the same input, the answer is the one of the
+// last run
+pub fn bench(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Params,
+    runs: usize,
+) -> Result<Benchmark, ParseError> {
+    let mut parse: Vec<Duration> = vec![];
+    let mut solve: Vec<Duration> = vec![];
+    let mut answer: Option<Answer> = None;
+
+    for _ in 0..runs.max(1) {
+        let (run_answer, timings) = solution.timed(input, params)?;
+
+        parse.push(timings.parse);
+        solve.push(timings.solve);
+        answer = Some(run_answer);
+    }
+
+    Ok(Benchmark {
+        answer: answer.expect("the solution runs at least once"),
+        parse: Stats::new(&parse).expect("the solution runs at least once"),
+        solve: Stats::new(&solve).expect("the solution runs at least once"),
+    })
+}
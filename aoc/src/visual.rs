@@ -0,0 +1,145 @@
+use std::{collections::HashMap, fs::File, io::{self, BufRead, BufWriter, Write}, path::Path, thread, time::Duration};
+use aoc_core::{visual::Color, Frame, Position};
+
+// Terminal
+// -----------------------------------------------------------------------------
+
+// Draws every frame over the previous one, `delay` apart or, without a delay, one line read from
+// `keys` apart (`q` stops)
+pub fn play(frames: &[Frame], delay: Option<Duration>, out: &mut impl Write, keys: &mut impl BufRead) -> io::Result<()> {
+    for (index, frame) in frames.iter().enumerate() {
+        write!(out, "\x1b[2J\x1b[H{}", frame.ansi())?;
+
+        match delay {
+            Some(delay) => {
+                out.flush()?;
+                thread::sleep(delay);
+            },
+            None if index + 1 < frames.len() => {
+                write!(out, "frame {}/{}, Enter for the next one or q to stop ", index + 1, frames.len())?;
+                out.flush()?;
+
+                let mut line = String::new();
+                if keys.read_line(&mut line)? == 0 || line.trim() == "q" {
+                    break;
+                }
+            },
+            None => {},
+        }
+    }
+
+    out.flush()
+}
+
+// Images
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Gif,
+    Apng,
+    // A still of the last frame
+    Png,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "gif" => Some(Self::Gif),
+            "apng" => Some(Self::Apng),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+}
+
+// Every cell becomes a `scale` × `scale` square of its color, the frames smaller than the largest
+// one are padded with black
+pub fn export(frames: &[Frame], path: &Path, format: Format, scale: usize, delay: Duration) -> io::Result<()> {
+    let frames = match format {
+        Format::Png => frames.last().map_or(&[][..], std::slice::from_ref),
+        Format::Gif | Format::Apng => frames,
+    };
+    if frames.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "there is no frame to export"));
+    }
+
+    let rows = frames.iter().map(|frame| frame.cells.rows()).max().unwrap_or(0);
+    let cols = frames.iter().map(|frame| frame.cells.cols()).max().unwrap_or(0);
+    let (width, height) = (cols * scale.max(1), rows * scale.max(1));
+    let images: Vec<Vec<Color>> = frames.iter().map(|frame| pixels(frame, width, height, scale.max(1))).collect();
+
+    let file = BufWriter::new(File::create(path)?);
+
+    match format {
+        Format::Gif => gif(file, &images, width, height, delay),
+        Format::Apng | Format::Png => png(file, &images, width, height, delay),
+    }
+}
+
+fn pixels(frame: &Frame, width: usize, height: usize, scale: usize) -> Vec<Color> {
+    (0..width * height)
+        .map(|i| Position::new(i / width / scale, i % width / scale))
+        .map(|position| frame.cells.get(position).map_or(Color::BLACK, |cell| cell.color))
+        .collect()
+}
+
+fn gif(file: impl Write, images: &[Vec<Color>], width: usize, height: usize, delay: Duration) -> io::Result<()> {
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{}x{} is too large for a GIF", width, height)));
+    };
+
+    let mut encoder = gif::Encoder::new(file, width, height, &[]).map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+    for image in images {
+        // The frames only use a handful of colors, they are quantized only when there are too many
+        let mut palette: HashMap<Color, u8> = HashMap::new();
+        for color in image {
+            if palette.len() <= 256 && !palette.contains_key(color) {
+                palette.insert(*color, palette.len() as u8);
+            }
+        }
+
+        let mut frame = if palette.len() <= 256 {
+            let mut colors: Vec<(&Color, &u8)> = palette.iter().collect();
+            colors.sort_by_key(|(_, index)| **index);
+
+            gif::Frame::from_palette_pixels(
+                width,
+                height,
+                image.iter().map(|color| palette[color]).collect::<Vec<u8>>(),
+                colors.iter().flat_map(|(color, _)| [color.r, color.g, color.b]).collect::<Vec<u8>>(),
+                None,
+            )
+        } else {
+            gif::Frame::from_rgb_speed(width, height, &rgb(image), 10)
+        };
+        frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+// A single image is a plain PNG, more make an APNG
+fn png(file: impl Write, images: &[Vec<Color>], width: usize, height: usize, delay: Duration) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    if images.len() > 1 {
+        encoder.set_animated(images.len() as u32, 0).map_err(io::Error::other)?;
+        encoder.set_frame_delay(delay.as_millis().min(u16::MAX as u128) as u16, 1000).map_err(io::Error::other)?;
+    }
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for image in images {
+        writer.write_image_data(&rgb(image)).map_err(io::Error::other)?;
+    }
+
+    writer.finish().map_err(io::Error::other)
+}
+
+fn rgb(image: &[Color]) -> Vec<u8> {
+    image.iter().flat_map(|color| [color.r, color.g, color.b]).collect()
+}
@@ -0,0 +1,70 @@
+use std::{fs, path::PathBuf};
+use aoc::{batch::{run_all, Source}, calendar::Status, ledger::Ledger, registry::{self, Entry}, store::Store};
+use aoc_core::Answer;
+
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+#[test]
+fn expands_directories_and_patterns() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in ["day-09-work.txt", "day-09-home.txt", "day-10.txt", ".day-09-swap.txt"] {
+        fs::write(dir.path().join(name), INPUT).unwrap();
+    }
+    fs::create_dir(dir.path().join("day-09-old.txt")).unwrap();
+    let store = Store::new(dir.path(), None);
+    let names = |pattern: PathBuf| -> Vec<String> {
+        Source::files(&pattern, &store)
+            .unwrap()
+            .iter()
+            .map(|source| source.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    };
+
+    assert_eq!(names(dir.path().to_path_buf()), ["day-09-home.txt", "day-09-work.txt", "day-10.txt"]);
+    assert_eq!(names(dir.path().join("day-09-*.txt")), ["day-09-home.txt", "day-09-work.txt"]);
+    assert_eq!(names(dir.path().join("day-1?.txt")), ["day-10.txt"]);
+    assert!(names(dir.path().join("day-2*")).is_empty());
+    // A plain path is taken as is, reading it reports whether it exists
+    assert_eq!(names(dir.path().join("missing.txt")), ["missing.txt"]);
+}
+
+#[test]
+fn finds_the_input_of_every_account() {
+    let root = tempfile::tempdir().unwrap();
+    Store::new(root.path(), Some("work")).add(2023, 9, INPUT).unwrap();
+    Store::new(root.path(), Some("home")).add(2023, 9, "1 2 3\n").unwrap();
+    Store::new(root.path(), Some("club")).add(2023, 10, ".\n").unwrap();
+    Store::new(root.path(), None).add(2023, 9, INPUT).unwrap();
+
+    assert_eq!(Store::accounts(root.path()).unwrap(), ["club", "home", "work"]);
+    let sources = Source::accounts(root.path(), 2023, 9).unwrap();
+    assert_eq!(sources.iter().map(|source| source.name.as_str()).collect::<Vec<_>>(), ["home", "work"]);
+}
+
+#[test]
+fn runs_every_part_against_every_input() {
+    let root = tempfile::tempdir().unwrap();
+    let (work, home) = (Store::new(root.path(), Some("work")), Store::new(root.path(), Some("home")));
+    work.add(2023, 9, INPUT).unwrap();
+    home.add(2023, 9, "1 2 3\n").unwrap();
+
+    // The same answer is right for one account and wrong for the other
+    let mut ledger = Ledger::open(&home, 2023).unwrap();
+    ledger.confirm(9, 1, "1 2 3\n", "5");
+    ledger.save().unwrap();
+
+    let mut sources = Source::accounts(root.path(), 2023, 9).unwrap();
+    sources.extend(Source::files(&root.path().join("missing.txt"), &work).unwrap());
+    let entries: Vec<&Entry> = [1, 2].map(|part| registry::find(2023, 9, part).unwrap()).to_vec();
+
+    let rows = run_all(&entries, &sources).unwrap();
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].source, "home");
+    assert_eq!(rows[0].reports[0].answer, Some(Answer::Int(4)));
+    assert_eq!(rows[0].reports[0].status.to_string(), "mismatch, disagrees with the confirmed answer 5");
+    assert_eq!(rows[0].reports[1].answer, Some(Answer::Int(0)));
+    assert_eq!(rows[1].reports.iter().map(|r| r.answer.clone()).collect::<Vec<_>>(), [Some(Answer::Int(114)), Some(Answer::Int(2))]);
+    assert!(rows[1].reports.iter().all(|r| matches!(r.status, Status::Ok) && r.elapsed.is_some()));
+    assert!(rows[2].reports.iter().all(|r| matches!(r.status, Status::Unreadable(_)) && r.elapsed.is_none()));
+}
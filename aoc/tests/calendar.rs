@@ -0,0 +1,77 @@
+use aoc::{calendar::{run_all, Status}, ledger::Ledger, registry::{self, Entry}, store::Store};
+use aoc_core::{Answer, ParseError, Stages};
+
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+#[test]
+fn checks_every_part_against_the_ledger() {
+    let (root, inputs) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+    let store = Store::new(inputs.path(), None);
+    store.add(2023, 9, INPUT).unwrap();
+    store.add(2023, 6, "Time: 7\n").unwrap();
+
+    let mut ledger = Ledger::open(&store, 2023).unwrap();
+    ledger.confirm(9, 2, INPUT, "3");
+    ledger.save().unwrap();
+
+    let entries: Vec<&Entry> = [(6, 1), (9, 1), (9, 2), (10, 1)]
+        .into_iter()
+        .map(|(day, part)| registry::find(2023, day, part).unwrap())
+        .collect();
+    let reports = run_all(&entries, &store, root.path()).unwrap();
+
+    assert_eq!(reports.iter().map(|r| (r.day, r.part)).collect::<Vec<_>>(), [(6, 1), (9, 1), (9, 2), (10, 1)]);
+    assert!(matches!(reports[0].status, Status::Unparsed(_)));
+    assert!(matches!(reports[1].status, Status::Ok));
+    assert_eq!(reports[1].answer, Some(Answer::Int(114)));
+    assert_eq!(reports[2].status.to_string(), "mismatch, disagrees with the confirmed answer 3");
+    assert!(matches!(reports[3].status, Status::NoInput));
+    assert!(!reports[3].status.is_failure());
+}
+
+#[test]
+fn isolates_panicking_parts() {
+    let (root, inputs) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+    let store = Store::new(inputs.path(), None);
+    store.add(2023, 9, INPUT).unwrap();
+
+    let panicking = Entry {
+        year: 2023,
+        day: 9,
+        part: 1,
+        solution: Box::leak(Box::new(Stages::new(
+            |input: &str| Ok::<_, ParseError>(input.len()),
+            |_: &usize| -> Answer { panic!("ran out of camels") },
+        ))),
+    };
+    let entries = [&panicking, registry::find(2023, 9, 2).unwrap()];
+
+    let reports = run_all(&entries, &store, root.path()).unwrap();
+
+    assert_eq!(reports[0].status.to_string(), "panicked, ran out of camels");
+    assert!(reports[0].status.is_failure());
+    assert_eq!(reports[1].answer, Some(Answer::Int(2)));
+}
+
+#[test]
+fn reports_overflowing_parts() {
+    let (root, inputs) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+    let store = Store::new(inputs.path(), None);
+    store.add(2023, 9, INPUT).unwrap();
+
+    let overflowing = Entry {
+        year: 2023,
+        day: 9,
+        part: 1,
+        solution: Box::leak(Box::new(Stages::new(
+            |input: &str| Ok::<_, ParseError>(input.len() as u8),
+            |len: &u8| -> Answer { (std::hint::black_box(*len) * 8).into() },
+        ))),
+    };
+
+    let reports = run_all(&[&overflowing], &store, root.path()).unwrap();
+
+    assert!(matches!(reports[0].status, Status::Overflowed(_)));
+    assert!(reports[0].status.to_string().starts_with("overflowed, attempt to multiply with overflow at "));
+    assert!(reports[0].status.is_failure());
+}
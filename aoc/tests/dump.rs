@@ -0,0 +1,134 @@
+#![cfg(feature = "dump")]
+
+use std::fmt;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use aoc::dump::{self, Format, Stage};
+use aoc::registry::{self, Entry};
+use aoc_core::{Params, ParseError};
+
+fn generated(day: u8, seed: u64) -> String {
+    let generator = registry::find_generator(2023, day).unwrap();
+
+    (generator.input)(&mut StdRng::seed_from_u64(seed), 30).input
+}
+
+fn parts(day: u8) -> Vec<&'static Entry> {
+    registry::ENTRIES.iter().filter(|e| e.year == 2023 && e.day == day).collect()
+}
+
+// The model read back from its JSON is the one that was written
+fn round_trip<T>(parse: fn(&str) -> Result<T, ParseError>, input: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+{
+    let parsed = parse(input).unwrap();
+    let json = serde_json::to_string(&parsed).unwrap();
+
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), parsed);
+}
+
+#[test]
+fn every_day_has_a_dump() {
+    for entry in registry::ENTRIES {
+        assert!(registry::find_dump(entry.year, entry.day).is_some(), "{} day {}", entry.year, entry.day);
+    }
+}
+
+#[test]
+fn reads_the_models_back() {
+    for seed in 0..3 {
+        round_trip(aoc_2023_day_01::parse, &generated(1, seed));
+        round_trip(aoc_2023_day_02::parse, &generated(2, seed));
+        round_trip(aoc_2023_day_03::parse, &generated(3, seed));
+        round_trip(aoc_2023_day_04::parse, &generated(4, seed));
+        round_trip(aoc_2023_day_05::parse, &generated(5, seed));
+        round_trip(aoc_2023_day_06::parse, &generated(6, seed));
+        round_trip(aoc_2023_day_07::parse, &generated(7, seed));
+        round_trip(aoc_2023_day_08::parse, &generated(8, seed));
+        round_trip(aoc_2023_day_09::parse, &generated(9, seed));
+        round_trip(aoc_2023_day_10::parse, &generated(10, seed));
+        round_trip(aoc_2023_day_11::parse, &generated(11, seed));
+        round_trip(aoc_2023_day_12::parse, &generated(12, seed));
+        round_trip(aoc_2023_day_13::parse, &generated(13, seed));
+        round_trip(aoc_2023_day_14::parse, &generated(14, seed));
+        round_trip(aoc_2023_day_15::parse, &generated(15, seed));
+        round_trip(aoc_2023_day_16::parse, &generated(16, seed));
+        round_trip(aoc_2023_day_17::parse, &generated(17, seed));
+        round_trip(aoc_2023_day_18::parse, &generated(18, seed));
+        round_trip(aoc_2023_day_19::parse, &generated(19, seed));
+        round_trip(aoc_2023_day_20::parse, &generated(20, seed));
+        round_trip(aoc_2023_day_21::parse, &generated(21, seed));
+        round_trip(aoc_2023_day_22::parse, &generated(22, seed));
+        round_trip(aoc_2023_day_23::parse, &generated(23, seed));
+        round_trip(aoc_2023_day_24::parse, &generated(24, seed));
+        round_trip(aoc_2023_day_25::parse, &generated(25, seed));
+    }
+}
+
+// Each parse fills its hash maps in another order, the dumps must not show it
+#[test]
+fn dumps_the_same_input_the_same_way() {
+    for dump in registry::DUMPS {
+        let input = generated(dump.day, 1);
+
+        // Solving every day takes long, and only the state of the days keeping one holds hash maps
+        let stages = if dump.solved.is_some() { &[Stage::Parsed, Stage::Solved][..] } else { &[Stage::Parsed] };
+
+        for &stage in stages {
+            let first = dump::dump(dump, &parts(dump.day), &input, stage, &Params::new()).unwrap();
+            let second = dump::dump(dump, &parts(dump.day), &input, stage, &Params::new()).unwrap();
+
+            assert_eq!(dump::write(&first, Format::Json), dump::write(&second, Format::Json), "day {}", dump.day);
+        }
+    }
+}
+
+#[test]
+fn solved_stage_holds_the_answers_and_the_state() {
+    for dump in registry::DUMPS.iter().filter(|dump| dump.solved.is_some()) {
+        let input = generated(dump.day, 2);
+        let solved = dump::dump(dump, &parts(dump.day), &input, Stage::Solved, &Params::new()).unwrap();
+
+        for entry in parts(dump.day) {
+            let answer = entry.solution.solve(&input, &Params::new()).unwrap().to_string();
+            assert_eq!(solved["answers"][entry.part.to_string()], Value::String(answer), "day {}", dump.day);
+        }
+        assert!(solved["state"].is_array() || solved["state"].is_object(), "day {}", dump.day);
+    }
+
+    let solved = dump::dump(registry::find_dump(2023, 1).unwrap(), &parts(1), &generated(1, 2), Stage::Solved, &Params::new()).unwrap();
+    assert!(solved.get("state").is_none());
+
+    let almanac = dump::dump(registry::find_dump(2023, 5).unwrap(), &[], &generated(5, 2), Stage::Parsed, &Params::new()).unwrap();
+    assert!(almanac["seed_to_soil"]["pieces"].is_array());
+}
+
+#[test]
+fn parts_that_cannot_answer_are_reported_apart() {
+    let dump = registry::find_dump(2023, 14).unwrap();
+    let params: Params = [("cycles", "3")].into_iter().collect();
+
+    let solved = dump::dump(dump, &parts(14), &generated(14, 0), Stage::Solved, &params).unwrap();
+
+    assert!(solved["answers"].get("1").is_none());
+    assert_eq!(solved["errors"]["1"], "unknown parameter `cycles`");
+    assert!(solved["answers"]["2"].is_string());
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    let dump = registry::find_dump(2023, 8).unwrap();
+
+    assert!(dump::dump(dump, &parts(8), "LR", Stage::Parsed, &Params::new()).is_err());
+}
+
+#[test]
+fn compact_format_fits_on_a_line() {
+    let dump = registry::find_dump(2023, 22).unwrap();
+    let parsed = dump::dump(dump, &[], &generated(22, 0), Stage::Parsed, &Params::new()).unwrap();
+
+    assert!(!dump::write(&parsed, Format::Compact).contains('\n'));
+    assert_eq!(serde_json::from_str::<Value>(&dump::write(&parsed, Format::Json)).unwrap(), parsed);
+}
@@ -0,0 +1,20 @@
+use std::path::Path;
+use aoc::fixtures::{self, Outcome};
+
+#[test]
+fn examples_match_their_expected_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let fixtures = fixtures::discover(root).unwrap();
+
+    assert!(!fixtures.is_empty());
+
+    let failures: Vec<String> = fixtures
+        .iter()
+        .filter_map(|fixture| match fixture.run().unwrap() {
+            Outcome::Passed | Outcome::Ignored(_) => None,
+            outcome => Some(format!("{}: {:?}", fixture, outcome)),
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,59 @@
+use rand::{rngs::StdRng, SeedableRng};
+use aoc::registry::{self, Generator};
+use aoc_core::{Generated, Params};
+
+fn generate(generator: &Generator, seed: u64, size: usize) -> Generated {
+    (generator.input)(&mut StdRng::seed_from_u64(seed), size)
+}
+
+#[test]
+fn every_day_has_a_generator() {
+    for entry in registry::ENTRIES {
+        assert!(registry::find_generator(entry.year, entry.day).is_some(), "{} day {}", entry.year, entry.day);
+    }
+}
+
+#[test]
+fn the_seed_makes_the_input() {
+    for generator in registry::GENERATORS {
+        assert_eq!(generate(generator, 7, 20), generate(generator, 7, 20), "day {}", generator.day);
+        assert_ne!(generate(generator, 7, 20).input, generate(generator, 8, 20).input, "day {}", generator.day);
+    }
+}
+
+// Stress test: the solutions take the inputs and find the answers known by construction. The days
+// relying on the layout of the puzzle's own inputs may refuse the others, but never get them wrong.
+#[test]
+fn solves_the_generated_inputs() {
+    for generator in registry::GENERATORS {
+        // The longest hike of day 23 grows too fast with the crossings
+        let size = match generator.day {
+            23 => 4,
+            _ => 30,
+        };
+
+        for seed in 0..3 {
+            let inputs = [Some(generator.input), generator.puzzle_like]
+                .into_iter()
+                .flatten()
+                .map(|input| input(&mut StdRng::seed_from_u64(seed), size));
+
+            for (generated, tailored) in inputs.zip([generator.puzzle_like.is_none(), true]) {
+                for part in [1, 2] {
+                    let Some(entry) = registry::find(generator.year, generator.day, part) else {
+                        continue;
+                    };
+                    let answer = match entry.solution.solve(&generated.input, &Params::new()) {
+                        Ok(answer) => answer,
+                        Err(_) if !tailored => continue,
+                        Err(e) => panic!("day {} part {} seed {}: {}\n{}", generator.day, part, seed, e, generated.input),
+                    };
+
+                    if let Some(expected) = generated.answer(part) {
+                        assert_eq!(&answer, expected, "day {} part {} seed {}", generator.day, part, seed);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use aoc::{ledger::{Hint, Ledger, Verdict, LEDGER}, store::Store};
+
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+#[test]
+fn keeps_answers_across_runs() {
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+
+    let mut ledger = Ledger::open(&store, 2023).unwrap();
+    ledger.reject(9, 1, INPUT, "100", Some(Hint::TooLow));
+    ledger.confirm(9, 1, INPUT, "114");
+    ledger.save().unwrap();
+
+    let ledger = Ledger::open(&store, 2023).unwrap();
+    let record = ledger.record(9, 1, INPUT).unwrap();
+
+    assert_eq!(record.answer.as_deref(), Some("114"));
+    assert_eq!(record.rejected[0].hint, Some(Hint::TooLow));
+    assert!(root.path().join("2023").join(LEDGER).is_file());
+}
+
+#[test]
+fn answers_belong_to_their_input() {
+    let root = tempfile::tempdir().unwrap();
+    let mut ledger = Ledger::open(&Store::new(root.path(), None), 2023).unwrap();
+
+    ledger.confirm(9, 1, INPUT, "114");
+
+    assert_eq!(ledger.check(9, 1, INPUT, "114"), Verdict::Confirmed);
+    assert_eq!(ledger.check(9, 1, "0 3 6 9 12 15\n", "114"), Verdict::Unknown);
+    assert_eq!(ledger.check(9, 2, INPUT, "114"), Verdict::Unknown);
+}
+
+#[test]
+fn flags_answers_known_to_be_wrong() {
+    let root = tempfile::tempdir().unwrap();
+    let mut ledger = Ledger::open(&Store::new(root.path(), None), 2023).unwrap();
+
+    ledger.reject(9, 1, INPUT, "200", Some(Hint::TooHigh));
+    ledger.reject(9, 1, INPUT, "100", Some(Hint::TooLow));
+    ledger.reject(9, 1, INPUT, "113", None);
+
+    assert_eq!(ledger.check(9, 1, INPUT, "113"), Verdict::Rejected { hint: None });
+    assert_eq!(ledger.check(9, 1, INPUT, "200"), Verdict::Rejected { hint: Some(Hint::TooHigh) });
+    assert_eq!(ledger.check(9, 1, INPUT, "250"), Verdict::OutOfRange { guess: "200".to_string(), hint: Hint::TooHigh });
+    assert_eq!(ledger.check(9, 1, INPUT, "42"), Verdict::OutOfRange { guess: "100".to_string(), hint: Hint::TooLow });
+    assert_eq!(ledger.check(9, 1, INPUT, "114"), Verdict::Unknown);
+
+    ledger.confirm(9, 1, INPUT, "114");
+
+    assert_eq!(ledger.check(9, 1, INPUT, "115"), Verdict::Disagrees { confirmed: "114".to_string() });
+}
+
+#[test]
+fn reads_hand_written_ledgers() {
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+    fs::create_dir_all(root.path().join("2023")).unwrap();
+    fs::write(
+        root.path().join("2023").join(LEDGER),
+        format!(
+            "[[answer]]\nday = 9\npart = 2\ninput = \"{}\"\n\n[[answer.rejected]]\nguess = \"-2\"\nhint = \"too-low\"\n",
+            aoc::store::sha256(INPUT.as_bytes())
+        ),
+    ).unwrap();
+
+    let ledger = Ledger::open(&store, 2023).unwrap();
+
+    assert!(ledger.check(9, 2, INPUT, "-5").is_wrong());
+    assert!(!ledger.check(9, 2, INPUT, "2").is_wrong());
+}
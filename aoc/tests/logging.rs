@@ -0,0 +1,56 @@
+use std::{io, sync::{Arc, Mutex}};
+use aoc::{logging::{subscriber, Format}, registry, timing};
+use aoc_core::Params;
+use tracing_subscriber::EnvFilter;
+
+const MODULES: &str = "broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a\n";
+
+// Keeps what the subscriber writes
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn traces(filter: &str) -> String {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let entry = registry::find(2023, 20, 1).unwrap();
+
+    tracing::subscriber::with_default(subscriber(EnvFilter::new(filter), Format::Json, move || writer.clone()), || {
+        entry.span().in_scope(|| timing::bench(entry.solution, MODULES, &Params::new(), 1)).unwrap();
+    });
+
+    let traces = buffer.0.lock().unwrap().clone();
+    String::from_utf8(traces).unwrap()
+}
+
+#[test]
+fn writes_json_lines_within_the_span_of_the_part() {
+    let lines: Vec<serde_json::Value> = traces("trace")
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let pulse = lines.iter().find(|line| line["fields"]["message"] == "pulse").unwrap();
+    assert_eq!(pulse["fields"]["source"], "button");
+    assert_eq!(pulse["spans"][0]["name"], "part");
+    assert_eq!(pulse["spans"][0]["day"], 20);
+    assert_eq!(pulse["spans"][1]["name"], "solve");
+
+    assert_eq!(lines.iter().filter(|line| line["fields"]["message"] == "button pressed").count(), 1000);
+    assert!(lines.iter().any(|line| line["span"]["name"] == "part" && line["fields"]["time.busy"].is_string()));
+}
+
+#[test]
+fn stays_quiet_when_off() {
+    assert_eq!(traces("off"), "");
+    assert!(!traces("debug").contains("\"pulse\""));
+}
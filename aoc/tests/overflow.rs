@@ -0,0 +1,20 @@
+use std::{hint::black_box, panic};
+use aoc::overflow;
+
+#[test]
+fn catches_overflows_with_their_location() {
+    let overflow = overflow::catch(|| black_box(i32::MAX) + 1).unwrap_err();
+
+    assert_eq!(overflow.operation, "add");
+    assert!(overflow.location.as_deref().is_some_and(|l| l.contains("overflow.rs")));
+    assert!(overflow.to_string().starts_with("attempt to add with overflow at "));
+
+    assert_eq!(overflow::catch(|| black_box(20_u8) * 2), Ok(40));
+}
+
+#[test]
+fn lets_other_panics_through() {
+    let caught = panic::catch_unwind(|| overflow::catch(|| panic!("ran out of camels")));
+
+    assert_eq!(caught.unwrap_err().downcast_ref::<&str>(), Some(&"ran out of camels"));
+}
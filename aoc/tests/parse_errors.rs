@@ -0,0 +1,95 @@
+use aoc::registry;
+use aoc_core::Params;
+
+fn parse_error(year: u16, day: u8, part: u8, input: &str) -> String {
+    let entry = registry::find(year, day, part).unwrap();
+
+    match entry.solution.solve(input, &Params::new()) {
+        Ok(answer) => panic!("{} day {} part {} accepted a malformed input, answered {}", year, day, part, answer),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn malformed_inputs_point_at_the_offending_token() {
+    assert_eq!(
+        parse_error(2023, 2, 1, "Game 1: 3 blue, 4 red\nGame 2: 1 purple\n"),
+        "line 2, column 11: unknown color `purple`"
+    );
+    assert_eq!(
+        parse_error(2023, 7, 1, "32T3K 765\nT55J5 xx\n"),
+        "line 2, column 7: expected a number, found `xx`"
+    );
+    assert_eq!(
+        parse_error(2023, 10, 2, "-L|F7\n7S-7|\nL|7?|\n"),
+        "line 3, column 4: unexpected character `?`"
+    );
+    assert_eq!(
+        parse_error(2023, 22, 1, "1,0,1~1,2,1\n0,0,2~2,0\n"),
+        "line 2, column 7: expected `<x>,<y>,<z>`, found `2,0`"
+    );
+}
+
+#[test]
+fn truncated_inputs_are_reported_instead_of_panicking() {
+    let truncated = [
+        (5, 1, "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n"),
+        (8, 1, "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\n"),
+        (19, 2, "px{a<2006:qkq,m>2090:A,rfg}\n"),
+        (24, 2, "19, 13, 30 @ -2,  1, -2\n"),
+    ];
+
+    for (day, part, input) in truncated {
+        parse_error(2023, day, part, input);
+    }
+}
+
+#[test]
+fn inputs_the_solutions_cannot_handle_are_reported() {
+    // The example hailstones are too slow for the default filter
+    assert_eq!(
+        parse_error(2023, 24, 2, "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n"),
+        "no pair of hailstones narrows the rock velocity on x"
+    );
+    assert_eq!(
+        parse_error(2023, 13, 1, "##\n..\n\n#.\n.#\n"),
+        "pattern 2 (line 4) has no line of reflection"
+    );
+    assert_eq!(
+        parse_error(2023, 6, 2, "Time:      70  150  300  400  500  600  700  800\nDistance:  10  100 1000 1000 1000 1000 1000 1000\n"),
+        "expected the kerned time to be a number, found `70150300400500600700800`"
+    );
+    assert_eq!(parse_error(2023, 8, 1, "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n"), "expected a `ZZZ` node");
+    assert_eq!(
+        parse_error(2023, 8, 1, "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (AAA, ZZZ)\n"),
+        "`ZZZ` cannot be reached from `AAA`"
+    );
+    assert_eq!(parse_error(2023, 8, 2, "L\n\nBBB = (BBB, BBB)\n"), "expected nodes ending with `A`");
+    assert_eq!(
+        parse_error(2023, 8, 2, "L\n\nAAA = (BBZ, BBZ)\nBBZ = (AAA, AAA)\nCCA = (CCB, CCB)\nCCB = (CCZ, CCZ)\nCCZ = (CCD, CCD)\nCCD = (CCA, CCA)\n"),
+        "the ghosts never all stand on nodes ending with `Z` at once"
+    );
+    // The ultra crucible cannot stop after fewer than 4 blocks in a straight line
+    assert_eq!(parse_error(2023, 17, 2, "111\n111\n111\n"), "no path reaches the bottom-right block");
+    assert_eq!(
+        parse_error(2023, 19, 2, "in{x<10:a,R}\na{m>5:b,A}\nb{a<3:in,R}\n"),
+        "line 1, column 1: workflows route in a loop: `in` → `a` → `b` → `in`"
+    );
+    // Rocks in the way along the middle row, the count of a long walk cannot be told from a short one
+    assert_eq!(
+        parse_error(2023, 21, 2, "...\n.S#\n...\n"),
+        "walking more than 1000 steps needs a square map with the start in the middle and no rock on its middle row, middle column and border"
+    );
+    assert!(parse_error(2023, 21, 2, ".....\n..S..\n.....\n").starts_with("walking more than 1000 steps needs a square map"));
+}
+
+#[test]
+fn parameters_contradicting_each_other_are_reported() {
+    let entry = registry::find(2023, 17, 2).unwrap();
+    let params: Params = [("min_straight", "5"), ("max_straight", "3")].into_iter().collect();
+
+    assert_eq!(
+        entry.solution.solve("111\n111\n111\n", &params).unwrap_err().message,
+        "invalid values for parameters `min_straight` and `max_straight`: 5 is more than 3"
+    );
+}
@@ -0,0 +1,103 @@
+use std::{fs, path::Path};
+use proptest::{prelude::*, sample::Index};
+use rand::{rngs::StdRng, SeedableRng};
+use aoc::{fixtures, registry::{self, Parser}};
+
+fn generated(parser: &Parser, seed: u64, size: usize) -> String {
+    let generator = registry::find_generator(parser.year, parser.day).unwrap();
+
+    (generator.input)(&mut StdRng::seed_from_u64(seed), size).input
+}
+
+// Mostly the characters the puzzles are made of, so that the damage gets past the first checks
+fn character() -> impl Strategy<Value = char> {
+    prop_oneof![
+        prop::sample::select(" \n,:;=@~-|.#?SO<>^v%&(){}0123456789LRJF7xmasAZ".chars().collect::<Vec<char>>()),
+        any::<char>(),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(Index, char),
+    Replace(Index, char),
+    Remove(Index),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<Index>(), character()).prop_map(|(index, c)| Edit::Insert(index, c)),
+        (any::<Index>(), character()).prop_map(|(index, c)| Edit::Replace(index, c)),
+        any::<Index>().prop_map(Edit::Remove),
+    ]
+}
+
+fn damage(input: &str, edits: &[Edit]) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for edit in edits {
+        let len = chars.len();
+
+        match *edit {
+            Edit::Insert(index, c) => chars.insert(index.index(len + 1), c),
+            Edit::Replace(index, c) if len > 0 => chars[index.index(len)] = c,
+            Edit::Remove(index) if len > 0 => {
+                chars.remove(index.index(len));
+            },
+            _ => {},
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+#[test]
+fn every_day_has_a_parser() {
+    for entry in registry::ENTRIES {
+        assert!(registry::find_parser(entry.year, entry.day).is_some(), "{} day {}", entry.year, entry.day);
+    }
+}
+
+// The examples of the puzzles are written back like the generated inputs
+#[test]
+fn renders_the_fixtures_back() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    for fixture in fixtures::discover(root).unwrap() {
+        let parser = registry::find_parser(fixture.year, fixture.day).unwrap();
+        let input = fs::read_to_string(&fixture.input).unwrap();
+
+        assert_eq!((parser.round_trip)(&input), Ok(()), "{}", fixture);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // Every model written back as puzzle text parses to the same model
+    #[test]
+    fn renders_the_models_back(seed in any::<u64>(), size in 1..30usize) {
+        for parser in registry::PARSERS {
+            let input = generated(parser, seed, size);
+
+            prop_assert_eq!((parser.round_trip)(&input), Ok(()), "day {}", parser.day);
+        }
+    }
+
+    // Inputs damaged in a few places are either still valid or rejected, the parsers never panic
+    #[test]
+    fn rejects_damaged_inputs(seed in any::<u64>(), size in 1..30usize, edits in prop::collection::vec(edit(), 1..4)) {
+        for parser in registry::PARSERS {
+            let input = damage(&generated(parser, seed, size), &edits);
+
+            let _ = (parser.parse)(&input);
+        }
+    }
+
+    #[test]
+    fn rejects_arbitrary_text(input in prop::collection::vec(character(), 0..200).prop_map(String::from_iter)) {
+        for parser in registry::PARSERS {
+            let _ = (parser.parse)(&input);
+        }
+    }
+}
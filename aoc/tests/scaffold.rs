@@ -0,0 +1,115 @@
+use std::{fs, path::Path};
+use aoc::scaffold::{new_day, readmes_from_html, write_readmes};
+
+// A copy of the parts of the workspace that `aoc new` edits
+fn workspace() -> tempfile::TempDir {
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let root = tempfile::tempdir().unwrap();
+
+    fs::create_dir_all(root.path().join("aoc").join("src")).unwrap();
+    fs::create_dir_all(root.path().join("fuzz")).unwrap();
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs", "fuzz/Cargo.toml"] {
+        fs::copy(repo.join(file), root.path().join(file)).unwrap();
+    }
+
+    root
+}
+
+#[test]
+fn creates_and_registers_a_new_day() {
+    let root = workspace();
+
+    let written = new_day(root.path(), 2024, 1).unwrap();
+
+    let day = root.path().join("2024").join("01");
+    assert!(written.contains(&day.join("src").join("lib.rs")));
+    assert!(written.contains(&day.join("src").join("generate.rs")));
+    assert!(day.join("puzzle-2").join("fixtures.toml").is_file());
+    assert!(fs::read_to_string(day.join("Cargo.toml")).unwrap().contains("name = \"aoc-2024-day-01\""));
+
+    let manifest = fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("\"2023/*\",\n    \"2024/*\",\n]"));
+
+    let dependencies = fs::read_to_string(root.path().join("aoc").join("Cargo.toml")).unwrap();
+    assert!(dependencies.contains("aoc-2023-day-25 = { path = \"../2023/25\" }\naoc-2024-day-01 = { path = \"../2024/01\" }\n"));
+    assert!(dependencies.contains("    \"aoc-2023-day-25/serde\",\n    \"aoc-2024-day-01/serde\",\n]"));
+
+    let registry = fs::read_to_string(root.path().join("aoc").join("src").join("registry.rs")).unwrap();
+    assert!(registry.contains(
+        "    2023, 25, 1 => aoc_2023_day_25::part1,\n    2024, 1, 1 => aoc_2024_day_01::part1,\n    2024, 1, 2 => aoc_2024_day_01::part2,\n"
+    ));
+    assert_eq!(registry.matches("    2023, 25 => aoc_2023_day_25,\n    2024, 1 => aoc_2024_day_01,\n}").count(), 3);
+
+    let fuzz = fs::read_to_string(root.path().join("fuzz").join("Cargo.toml")).unwrap();
+    assert!(fuzz.contains("aoc-2023-day-25 = { path = \"../2023/25\" }\naoc-2024-day-01 = { path = \"../2024/01\" }\n"));
+    assert!(fuzz.ends_with("[[bin]]\nname = \"parse_2024_01\"\npath = \"fuzz_targets/parse_2024_01.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+    assert!(fs::read_to_string(root.path().join("fuzz").join("fuzz_targets").join("parse_2024_01.rs")).unwrap().contains("aoc_2024_day_01::parse(input)"));
+}
+
+#[test]
+fn keeps_the_days_in_order() {
+    let root = workspace();
+    let registry = root.path().join("aoc").join("src").join("registry.rs");
+    // Make room for a day in the middle of the year
+    let without_day_9 = fs::read_to_string(&registry)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.contains("aoc_2023_day_09"))
+        .map(|line| format!("{}\n", line))
+        .collect::<String>();
+    fs::write(&registry, without_day_9).unwrap();
+
+    new_day(root.path(), 2023, 9).unwrap();
+
+    let registry = fs::read_to_string(&registry).unwrap();
+    assert!(registry.contains("2023, 8, 2 => aoc_2023_day_08::part2,\n    2023, 9, 1 => aoc_2023_day_09::part1,"));
+    assert!(registry.contains("2023, 9, 2 => aoc_2023_day_09::part2,\n    2023, 10, 1 =>"));
+    assert_eq!(registry.matches("2023, 8 => aoc_2023_day_08,\n    2023, 9 => aoc_2023_day_09,\n    2023, 10 => aoc_2023_day_10,").count(), 2);
+    assert!(registry.contains("2023, 8 => aoc_2023_day_08 solved,\n    2023, 9 => aoc_2023_day_09,\n    2023, 10 => aoc_2023_day_10,"));
+}
+
+#[test]
+fn refuses_to_overwrite_a_registered_day() {
+    let root = workspace();
+
+    let error = new_day(root.path(), 2023, 9).unwrap_err();
+
+    assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+    assert!(!root.path().join("2023").join("09").join("src").exists());
+}
+
+#[test]
+fn converts_puzzle_pages_to_readmes() {
+    let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2><p>You ride the <em>camel</em> through
+the sandstorm &amp; stop.</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+</code></pre>
+<ul>
+<li>Sum &lt;these&gt; values.</li>
+</ul>
+<p>What is the sum?</p>
+</article>
+<p>Your puzzle answer was <code>114</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Go <em>backwards</em>.</p>
+</article>
+</main></body></html>"#;
+
+    assert_eq!(
+        readmes_from_html(html),
+        [
+            "--- Day 9: Mirage Maintenance ---\nYou ride the camel through the sandstorm & stop.\n\n0 3 6 9 12 15\n1 3 6 10 15 21\nSum <these> values.\nWhat is the sum?\n",
+            "--- Part Two ---\nGo backwards.\n",
+        ]
+    );
+
+    let root = tempfile::tempdir().unwrap();
+    let written = write_readmes(root.path(), 2023, 9, html).unwrap();
+
+    assert_eq!(written.len(), 2);
+    assert_eq!(
+        fs::read_to_string(root.path().join("2023").join("09").join("puzzle-2").join("README.md")).unwrap(),
+        "--- Part Two ---\nGo backwards.\n"
+    );
+}
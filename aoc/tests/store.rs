@@ -0,0 +1,110 @@
+use std::{fs, io::{BufRead, BufReader, Write}, net::TcpListener, thread};
+use aoc::{find_input, store::{HttpFetcher, Integrity, Store, MANIFEST}};
+
+#[test]
+fn stores_inputs_by_year_and_day() {
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+
+    let path = store.add(2023, 5, "seeds: 79 14 55 13\n").unwrap();
+
+    assert_eq!(path, root.path().join("2023").join("05.txt"));
+    assert_eq!(store.get(2023, 5).unwrap().as_deref(), Some("seeds: 79 14 55 13\n"));
+    assert_eq!(store.get(2023, 6).unwrap(), None);
+    assert_eq!(store.list().unwrap().iter().map(|input| (input.year, input.day)).collect::<Vec<_>>(), [(2023, 5)]);
+}
+
+#[test]
+fn keeps_the_inputs_of_every_account_apart() {
+    let root = tempfile::tempdir().unwrap();
+    let (work, home) = (Store::new(root.path(), Some("work")), Store::new(root.path(), Some("home")));
+
+    work.add(2023, 1, "1abc2\n").unwrap();
+
+    assert_eq!(home.get(2023, 1).unwrap(), None);
+    assert!(root.path().join("work").join("2023").join("01.txt").is_file());
+    // Without an account, the account folders are not mistaken for years
+    assert!(Store::new(root.path(), None).list().unwrap().is_empty());
+}
+
+#[test]
+fn writes_manifests_readable_by_sha256sum() {
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+
+    store.add(2023, 9, "abc").unwrap();
+
+    assert_eq!(
+        fs::read_to_string(root.path().join("2023").join(MANIFEST)).unwrap(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  09.txt\n"
+    );
+}
+
+#[test]
+fn verify_reports_modified_missing_and_untracked_inputs() {
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+
+    store.add(2023, 1, "intact\n").unwrap();
+    store.add(2023, 2, "original\n").unwrap();
+    store.add(2023, 3, "deleted\n").unwrap();
+    fs::write(store.path(2023, 2), "tampered\n").unwrap();
+    fs::remove_file(store.path(2023, 3)).unwrap();
+    fs::write(store.path(2023, 4), "copied by hand\n").unwrap();
+
+    let report: Vec<(u8, Integrity)> = store
+        .verify()
+        .unwrap()
+        .into_iter()
+        .map(|(input, integrity)| (input.day, integrity))
+        .collect();
+
+    assert!(matches!(
+        report[..],
+        [(1, Integrity::Intact), (2, Integrity::Modified { .. }), (3, Integrity::Missing), (4, Integrity::Untracked)]
+    ));
+}
+
+#[test]
+fn fetches_inputs_from_the_given_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    // Stand-in for the puzzle server, answering a single request
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|line| !line.is_empty())
+            .collect();
+
+        stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 12\r\nConnection: close\r\n\r\n0 3 6 9 12\n\n").unwrap();
+        request
+    });
+
+    let root = tempfile::tempdir().unwrap();
+    let store = Store::new(root.path(), None);
+    store.fetch(&HttpFetcher::new("53551").with_base_url(url), 2023, 9).unwrap();
+
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /2023/day/9/input HTTP/1.1");
+    assert!(request.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=53551")));
+    assert_eq!(store.get(2023, 9).unwrap().as_deref(), Some("0 3 6 9 12\n\n"));
+    assert_eq!(store.verify().unwrap()[0].1, Integrity::Intact);
+}
+
+#[test]
+fn runner_prefers_the_stored_input() {
+    let (root, inputs) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+    let store = Store::new(inputs.path(), None);
+    let loose = root.path().join("2023").join("09").join("puzzle-1").join("input.txt");
+    fs::create_dir_all(loose.parent().unwrap()).unwrap();
+    fs::write(&loose, "0 3 6 9 12 15\n").unwrap();
+
+    assert_eq!(find_input(&store, root.path(), 2023, 9, 2), Some(loose));
+
+    let stored = store.add(2023, 9, "0 3 6 9 12 15\n").unwrap();
+
+    assert_eq!(find_input(&store, root.path(), 2023, 9, 2), Some(stored));
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+use aoc::{registry, timing::{self, Stats}};
+use aoc_core::Params;
+
+fn ms(ms: &[u64]) -> Vec<Duration> {
+    ms.iter().map(|ms| Duration::from_millis(*ms)).collect()
+}
+
+#[test]
+fn summarises_samples() {
+    let stats = Stats::new(&ms(&[5, 1, 3])).unwrap();
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.max, Duration::from_millis(5));
+
+    assert_eq!(Stats::new(&ms(&[4, 1, 2, 8])).unwrap().median, Duration::from_millis(3));
+    assert_eq!(Stats::new(&[]), None);
+}
+
+#[test]
+fn formats_single_runs_without_a_range() {
+    assert_eq!(Stats::new(&ms(&[2])).unwrap().to_string(), "2.00ms");
+    assert_eq!(Stats::new(&ms(&[1, 2, 4])).unwrap().to_string(), "1.00ms / 2.00ms / 4.00ms");
+}
+
+#[test]
+fn bench_keeps_the_answer() {
+    let entry = registry::find(2023, 9, 1).unwrap();
+    let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+    let benchmark = timing::bench(entry.solution, input, &Params::new(), 3).unwrap();
+
+    assert_eq!(benchmark.answer.to_string(), "114");
+}
@@ -0,0 +1,58 @@
+use std::{fs::File, time::Duration};
+use aoc::{registry, visual::{export, play, Format}};
+use aoc_core::Frame;
+
+const PLATFORM: &str = "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....\n";
+
+fn spin_cycles() -> Vec<Frame> {
+    (registry::find_visual(2023, 14).unwrap().frames)(PLATFORM, 2).unwrap()
+}
+
+#[test]
+fn steps_through_the_frames_until_asked_to_stop() {
+    let frames = spin_cycles();
+    let mut out: Vec<u8> = vec![];
+
+    play(&frames, None, &mut out, &mut "\nq\n".as_bytes()).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(frames.len() > 3);
+    assert_eq!(out.matches("\x1b[2J").count(), 2);
+    assert!(out.contains(&format!("frame 2/{}", frames.len())));
+}
+
+#[test]
+fn exports_animated_gifs() {
+    let frames = spin_cycles();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("14.gif");
+
+    export(&frames, &path, Format::Gif, 3, Duration::from_millis(50)).unwrap();
+
+    let mut decoder = gif::DecodeOptions::new().read_info(File::open(&path).unwrap()).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (30, 30));
+
+    let mut delays: Vec<u16> = vec![];
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        delays.push(frame.delay);
+    }
+    assert_eq!(delays, vec![5; frames.len()]);
+}
+
+#[test]
+fn exports_apngs_and_png_stills() {
+    let frames = spin_cycles();
+    let dir = tempfile::tempdir().unwrap();
+
+    for (name, format, count) in [("14.apng", Format::Apng, frames.len()), ("14.png", Format::Png, 1)] {
+        let path = dir.path().join(name);
+        assert_eq!(Format::from_path(&path), Some(format));
+
+        export(&frames, &path, format, 2, Duration::from_millis(50)).unwrap();
+
+        let reader = png::Decoder::new(File::open(&path).unwrap()).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (20, 20));
+        assert_eq!(info.animation_control.map_or(1, |control| control.num_frames as usize), count);
+    }
+}
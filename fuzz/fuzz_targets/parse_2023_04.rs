@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Malformed inputs must be rejected with a `ParseError`, never a panic
+fuzz_target!(|input: &str| {
+    let _ = aoc_2023_day_04::parse(input);
+});